@@ -0,0 +1,22 @@
+pub mod start;
+pub mod stop;
+
+use clap::{Parser, Subcommand};
+
+use start::StartArgs;
+use stop::StopArgs;
+
+#[derive(Debug, Parser)]
+#[command(name = "devbox", about = "Start and manage your local dev stack")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// Start a project's services
+    Start(StartArgs),
+    /// Stop a project's running services
+    Stop(StopArgs),
+}
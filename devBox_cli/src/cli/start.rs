@@ -1,10 +1,37 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::process::Stdio;
 
 use clap::Args;
+use colored::{Color, Colorize};
 use crate::error::{Result, ToolError};
-use  crate::config::yaml_parser::{ProjectConfig, Service};
-use tokio::process::Command;
-use log::{info, debug};
+use  crate::config::yaml_parser::{ProjectConfig, RestartPolicy, Service};
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{Child, Command};
+use log::{info, debug, warn};
+use std::time::SystemTime;
+use tokio::time::sleep;
+
+use crate::process::state::{RunState, ServiceState};
+
+/// Colors cycled across services (by their order in the config) so each
+/// one's multiplexed log output gets a stable, distinguishable tag.
+const COLOR_PALETTE: &[Color] = &[
+    Color::Cyan,
+    Color::Magenta,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::BrightCyan,
+    Color::BrightMagenta,
+    Color::BrightGreen,
+];
+
+/// One supervised child's exit, tagged with the service name it belongs to
+/// so the supervisor can look up its restart policy.
+type Waiter = Pin<Box<dyn Future<Output = (String, std::io::Result<std::process::ExitStatus>)> + Send>>;
 
 #[derive(Debug, Args, Clone)]
 pub struct StartArgs {
@@ -110,21 +137,26 @@ impl StartArgs {
         }
         
         if let Some(services) = &project.commands.start.services {
+            let start_order = self.resolve_start_order(&services.services)?;
+            println!("Start order: {}", start_order.join(" -> "));
+
             println!("Services:");
             for service in &services.services {
                 if self.should_start_service(service) {
                     if self.verbose {
-                        info!("  ✅ {}: {} (dir: {:?}, deps: {:?})", 
+                        info!("  ✅ {}: {} (dir: {:?}, deps: {:?})",
                             service.name, service.command, service.working_dir, service.dependencies);
                     }
                     println!("  ✅ {}: {}", service.name, service.command);
+                } else if Self::host_mismatch(service) {
+                    println!("  ❌ {}: (skipped - host mismatch, hosts: {:?})", service.name, service.hosts);
                 } else {
                     println!("  ❌ {}: (skipped)", service.name);
                 }
             }
         }
-        
-        Ok(())     
+
+        Ok(())
     }
 
     fn should_start_service(&self, service: &Service) -> bool {
@@ -139,59 +171,444 @@ impl StartArgs {
             return false
         }
     }
+
+    if Self::host_mismatch(service) {
+        return false
+    }
+
     true
 }
 
-    async fn run_service_command(&self, service: &Service, env_vars: &HashMap<String, String>) -> Result<()> {
+    /// True when `service.hosts` is non-empty and none of its entries match
+    /// the current machine, identified by the `HOST` env var or, failing
+    /// that, the system hostname.
+    fn host_mismatch(service: &Service) -> bool {
+        !service.hosts.is_empty() && !service.hosts.iter().any(|h| h == &Self::current_hostname())
+    }
+
+    fn current_hostname() -> String {
+        std::env::var("HOST").unwrap_or_else(|_| {
+            hostname::get()
+                .ok()
+                .and_then(|name| name.into_string().ok())
+                .unwrap_or_else(|| "unknown-host".to_string())
+        })
+    }
+
+    /// Resolves the order `services` should be started in from their
+    /// `dependencies`, via Kahn's algorithm, restricted to the services
+    /// that survive `--only`/`--skip` filtering. Errors rather than
+    /// guessing when: a `dependencies` entry names a service that doesn't
+    /// exist at all, a kept service depends on one that was pruned, or the
+    /// dependency graph has a cycle.
+    fn resolve_start_order(&self, services: &[Service]) -> Result<Vec<String>> {
+        let all_names: HashSet<&str> = services.iter().map(|s| s.name.as_str()).collect();
+        for service in services {
+            for dep in &service.dependencies {
+                if !all_names.contains(dep.as_str()) {
+                    return Err(ToolError::ConfigError(format!(
+                        "service '{}' depends on unknown service '{}'",
+                        service.name, dep
+                    )));
+                }
+            }
+        }
+
+        let kept: Vec<&Service> = services.iter().filter(|s| self.should_start_service(s)).collect();
+        let kept_names: HashSet<&str> = kept.iter().map(|s| s.name.as_str()).collect();
+
+        for service in &kept {
+            for dep in &service.dependencies {
+                if !kept_names.contains(dep.as_str()) {
+                    return Err(ToolError::ConfigError(format!(
+                        "service '{}' depends on '{}', which was excluded by --only/--skip",
+                        service.name, dep
+                    )));
+                }
+            }
+        }
+
+        let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+        let mut in_degree: HashMap<String, usize> = HashMap::new();
+        for service in &kept {
+            edges.entry(service.name.clone()).or_default();
+            in_degree.entry(service.name.clone()).or_insert(0);
+        }
+        for service in &kept {
+            for dep in &service.dependencies {
+                edges.get_mut(dep).unwrap().push(service.name.clone());
+                *in_degree.get_mut(&service.name).unwrap() += 1;
+            }
+        }
+
+        // Seed with zero-in-degree nodes in file order, so services with no
+        // dependencies keep their config ordering rather than being shuffled.
+        let mut queue: VecDeque<String> = kept
+            .iter()
+            .map(|s| s.name.clone())
+            .filter(|name| in_degree[name] == 0)
+            .collect();
+
+        let mut order = Vec::with_capacity(kept.len());
+        while let Some(name) = queue.pop_front() {
+            for dependent in &edges[&name] {
+                let degree = in_degree.get_mut(dependent).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(dependent.clone());
+                }
+            }
+            order.push(name);
+        }
+
+        if order.len() < kept.len() {
+            let started: HashSet<&str> = order.iter().map(|s| s.as_str()).collect();
+            let remaining: Vec<&str> = kept_names.into_iter().filter(|n| !started.contains(n)).collect();
+            return Err(ToolError::ConfigError(format!(
+                "dependency cycle detected among services: {}",
+                remaining.join(", ")
+            )));
+        }
+
+        Ok(order)
+    }
+
+    /// Launches `service` and returns its `Child` as soon as the process
+    /// exists, without waiting for it to finish. A long-running server
+    /// (web server, DB) would otherwise block every dependent service
+    /// after it from ever starting. stdout/stderr are piped and streamed
+    /// line-by-line through a `[name]`-prefixed, per-service colored tag
+    /// (see `stream_output`) rather than left to inherit the terminal,
+    /// where several services' output would interleave unattributed.
+    /// `service.command`, `service.working_dir`, and `env_vars`' values are
+    /// passed through `expand_vars` first, against the process env merged
+    /// with `env_vars` itself, so a config can reference `${VAR}`/`$VAR`
+    /// instead of hardcoding ports, paths, and credentials.
+    async fn run_service_command(
+        &self,
+        service: &Service,
+        env_vars: &HashMap<String, String>,
+        color: Color,
+        prefix_width: usize,
+    ) -> Result<Child> {
         info!("Starting service: {}", service.name);
-        
+
+        let merged_env: HashMap<String, String> = std::env::vars()
+            .chain(env_vars.iter().map(|(k, v)| (k.clone(), v.clone())))
+            .collect();
+
+        let command_str = Self::expand_vars(&service.command, &merged_env)?;
+
         let mut command = Command::new("sh");
-        command.arg("-c").arg(&service.command);
-        
+        command.arg("-c").arg(&command_str);
+
         // Set working directory
         if let Some(working_dir) = &service.working_dir {
-            command.current_dir(working_dir);
+            let working_dir = Self::expand_vars(working_dir, &merged_env)?;
+            command.current_dir(&working_dir);
             debug!("Working directory: {}", working_dir);
         }
-        
+
         // Set environment variables
         for (key, value) in env_vars {
-            command.env(key, value);
+            let value = Self::expand_vars(value, &merged_env)?;
+            command.env(key, &value);
             debug!("  Env: {}={}", key, value);
         }
-        
+
+        command.stdout(Stdio::piped());
+        command.stderr(Stdio::piped());
+
         // Execute command
         let mut child = command.spawn()
             .map_err(|e| ToolError::ProcessError(format!("Failed to start service {}: {}", service.name, e)))?;
-        
-        // Wait for completion
-        let status = child.wait().await
-            .map_err(|e| ToolError::ProcessError(format!("Service {} failed: {}", service.name, e)))?;
-        
-        if status.success() {
-            info!("Service {} started successfully", service.name);
-            Ok(())
-        } else {
-            Err(ToolError::ProcessError(format!(
-                "Service {} exited with code: {:?}",
-                service.name, status.code()
-            )))
+
+        let prefix = format!("[{:<width$}]", service.name, width = prefix_width);
+        if let Some(stdout) = child.stdout.take() {
+            Self::stream_output(prefix.clone(), color, stdout, false, true);
+        }
+        // Always drain stderr, even when it isn't printed: leaving it
+        // un-taken means the OS pipe buffer fills once the child writes
+        // enough to it, and the child blocks forever on its next write.
+        if let Some(stderr) = child.stderr.take() {
+            Self::stream_output(prefix, color, stderr, true, self.verbose);
+        }
+
+        info!("Service {} spawned", service.name);
+        Ok(child)
+    }
+
+    /// Expands `${VAR}`, `${VAR:-fallback}`, and bare `$VAR` references in
+    /// `input` against `env`. A reference with no fallback and no entry in
+    /// `env` is an error rather than silently expanding to an empty string.
+    fn expand_vars(input: &str, env: &HashMap<String, String>) -> Result<String> {
+        let chars: Vec<char> = input.chars().collect();
+        let mut out = String::with_capacity(input.len());
+        let mut i = 0;
+
+        while i < chars.len() {
+            if chars[i] != '$' || i + 1 >= chars.len() {
+                out.push(chars[i]);
+                i += 1;
+                continue;
+            }
+
+            if chars[i + 1] == '{' {
+                let end = chars[i + 2..]
+                    .iter()
+                    .position(|&c| c == '}')
+                    .map(|p| i + 2 + p)
+                    .ok_or_else(|| {
+                        ToolError::ConfigError(format!("unterminated '${{' in '{}'", input))
+                    })?;
+
+                let body: String = chars[i + 2..end].iter().collect();
+                let (name, fallback) = match body.split_once(":-") {
+                    Some((name, fallback)) => (name, Some(fallback)),
+                    None => (body.as_str(), None),
+                };
+
+                out.push_str(&Self::resolve_var(name, fallback, env, input)?);
+                i = end + 1;
+            } else if chars[i + 1].is_alphabetic() || chars[i + 1] == '_' {
+                let start = i + 1;
+                let mut end = start;
+                while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                    end += 1;
+                }
+
+                let name: String = chars[start..end].iter().collect();
+                out.push_str(&Self::resolve_var(&name, None, env, input)?);
+                i = end;
+            } else {
+                out.push(chars[i]);
+                i += 1;
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn resolve_var(
+        name: &str,
+        fallback: Option<&str>,
+        env: &HashMap<String, String>,
+        input: &str,
+    ) -> Result<String> {
+        match env.get(name) {
+            Some(value) => Ok(value.clone()),
+            None => fallback.map(str::to_string).ok_or_else(|| {
+                ToolError::ConfigError(format!(
+                    "undefined variable '{}' referenced in '{}' has no default",
+                    name, input
+                ))
+            }),
         }
     }
 
+    /// Reads `pipe` line-by-line, always draining it so the child never
+    /// blocks on a full pipe buffer, and echoes each line prefixed with
+    /// `prefix` in `color` when `show` is true. stderr (`is_stderr`) is
+    /// still read in full when `show` is false (i.e. `--verbose` wasn't
+    /// passed) - it just isn't printed.
+    fn stream_output(
+        prefix: String,
+        color: Color,
+        pipe: impl tokio::io::AsyncRead + Unpin + Send + 'static,
+        is_stderr: bool,
+        show: bool,
+    ) {
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(pipe).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if !show {
+                    continue;
+                }
+                let tag = prefix.color(color).bold();
+                if is_stderr {
+                    eprintln!("{} {}", tag, line);
+                } else {
+                    println!("{} {}", tag, line);
+                }
+            }
+        });
+    }
+
     async fn start_services(&self, project: &ProjectConfig) -> Result<()> {
     let env_vars = project.environment.clone().unwrap_or_default();
-    
-    if let Some(services) = &project.commands.start.services {
-        for service in &services.services {
-            if self.should_start_service(service) {
-                self.run_service_command(service, &env_vars).await?;
+
+    let Some(services) = &project.commands.start.services else {
+        info!("No services configured to start");
+        return Ok(());
+    };
+
+    let start_order = self.resolve_start_order(&services.services)?;
+    let by_name: HashMap<&str, &Service> =
+        services.services.iter().map(|s| (s.name.as_str(), s)).collect();
+
+    // Assign each service a stable color and a shared prefix width (the
+    // longest service name) up front, so a later restart keeps tagging a
+    // service's output the same way it started out.
+    let colors: HashMap<&str, Color> = services.services.iter().enumerate()
+        .map(|(i, s)| (s.name.as_str(), COLOR_PALETTE[i % COLOR_PALETTE.len()]))
+        .collect();
+    let prefix_width = services.services.iter().map(|s| s.name.len()).max().unwrap_or(0);
+
+    // Spawn every service in dependency order, keeping each `Child` handle
+    // around instead of waiting for it to complete, so dependents can
+    // start as soon as their dependency has launched rather than once it
+    // exits.
+    let mut children: HashMap<String, Child> = HashMap::with_capacity(start_order.len());
+    for name in &start_order {
+        let service = by_name[name.as_str()];
+        let child = self.run_service_command(service, &env_vars, colors[name.as_str()], prefix_width).await?;
+        children.insert(name.clone(), child);
+    }
+
+    info!("All {} service(s) started!", children.len());
+
+    // Track each service's current pid outside of `children` so a later
+    // restart can update just that one entry and re-save the whole set;
+    // `devbox stop` only ever reads this file, never the live `Child`s.
+    let mut pids: HashMap<String, u32> = children
+        .iter()
+        .map(|(name, child)| (name.clone(), child.id().unwrap_or(0)))
+        .collect();
+    Self::save_state(project, &by_name, &pids)?;
+
+    // Stay attached and supervise even in background mode: the caller
+    // (`start_in_background`) has already detached this whole call onto its
+    // own task, so supervising here is what keeps a flaky service restarted
+    // for as long as that background task runs.
+    self.supervise(project, &by_name, &env_vars, &colors, prefix_width, children, &mut pids).await
+    }
+
+    /// Stays attached to every spawned service for as long as any of them
+    /// is still running, reporting each one's exit and code as it happens
+    /// and consulting its `restart` policy: `always` relaunches
+    /// unconditionally, `on-failure` only after a non-zero exit, backing
+    /// off exponentially (capped at `max_delay_secs`) and giving up once a
+    /// service has failed more than `max_retries` times *in a row* - a clean exit resets the
+    /// counter, so an `always`-restarted job that keeps exiting 0 is
+    /// relaunched forever instead of tripping the retry cap. A `no`-policy
+    /// service that fails still ends supervision with an error, as before.
+    async fn supervise(
+        &self,
+        project: &ProjectConfig,
+        by_name: &HashMap<&str, &Service>,
+        env_vars: &HashMap<String, String>,
+        colors: &HashMap<&str, Color>,
+        prefix_width: usize,
+        children: HashMap<String, Child>,
+        pids: &mut HashMap<String, u32>,
+    ) -> Result<()> {
+        let mut waiters: FuturesUnordered<Waiter> = children
+            .into_iter()
+            .map(|(name, child)| Self::wait_for(name, child))
+            .collect();
+
+        let mut failure_counts: HashMap<String, u32> = HashMap::new();
+
+        while let Some((name, result)) = waiters.next().await {
+            let service = by_name[name.as_str()];
+            let succeeded = matches!(&result, Ok(status) if status.success());
+
+            match &result {
+                Ok(status) if status.success() => info!("Service {} exited successfully", name),
+                Ok(status) => warn!("Service {} exited with code: {:?}", name, status.code()),
+                Err(e) => warn!("Service {} failed: {}", name, e),
             }
+
+            let should_restart = match service.restart {
+                RestartPolicy::Always => true,
+                RestartPolicy::OnFailure => !succeeded,
+                RestartPolicy::No => false,
+            };
+
+            if !should_restart {
+                if !succeeded {
+                    return Err(ToolError::ProcessError(format!(
+                        "Service {} exited with a failure and its restart policy does not retry",
+                        name
+                    )));
+                }
+                continue;
+            }
+
+            // Only consecutive failures count toward `max_retries`; a clean
+            // exit resets the counter so an `always`-restarted service that
+            // keeps exiting 0 (a periodic job) never "gives up".
+            let attempt = if succeeded {
+                failure_counts.remove(&name);
+                0
+            } else {
+                let count = failure_counts.entry(name.clone()).or_insert(0);
+                *count += 1;
+                *count
+            };
+
+            if !succeeded && attempt > service.max_retries {
+                return Err(ToolError::ProcessError(format!(
+                    "Service {} failed {} times in a row, giving up",
+                    name, attempt
+                )));
+            }
+
+            if succeeded {
+                info!("Restarting service {} (clean exit, restart policy: always)", name);
+            } else {
+                let exponent = attempt.saturating_sub(1).min(16);
+                let backoff_secs = service
+                    .restart_delay
+                    .saturating_mul(2u64.saturating_pow(exponent))
+                    .min(service.max_delay_secs);
+
+                info!(
+                    "Restarting service {} in {}s (attempt {}/{})",
+                    name, backoff_secs, attempt, service.max_retries
+                );
+                sleep(std::time::Duration::from_secs(backoff_secs)).await;
+            }
+
+            let child = self
+                .run_service_command(service, env_vars, colors[name.as_str()], prefix_width)
+                .await?;
+            pids.insert(name.clone(), child.id().unwrap_or(0));
+            Self::save_state(project, by_name, pids)?;
+            waiters.push(Self::wait_for(name, child));
         }
+
+        Ok(())
     }
-    
-    info!("All services started successfully!");
-    Ok(())
+
+    fn wait_for(name: String, mut child: Child) -> Waiter {
+        Box::pin(async move {
+            let result = child.wait().await;
+            (name, result)
+        })
+    }
+
+    /// Rebuilds the full `RunState` from `pids`, so `devbox stop` always
+    /// sees each service's current pid even after a restart replaced it.
+    fn save_state(
+        project: &ProjectConfig,
+        by_name: &HashMap<&str, &Service>,
+        pids: &HashMap<String, u32>,
+    ) -> Result<()> {
+        let service_states: Vec<ServiceState> = pids
+            .iter()
+            .map(|(name, &pid)| {
+                let service = by_name[name.as_str()];
+                ServiceState {
+                    name: service.name.clone(),
+                    pid,
+                    command: service.command.clone(),
+                    start_time: SystemTime::now(),
+                    dependencies: service.dependencies.clone(),
+                }
+            })
+            .collect();
+        RunState::save(&project.name, service_states)
     }
 
     async fn start_in_background(&self, project: ProjectConfig) -> Result<()> {
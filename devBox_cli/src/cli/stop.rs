@@ -0,0 +1,164 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+use clap::Args;
+use log::{info, warn};
+use tokio::time::{sleep, Instant};
+
+use crate::error::Result;
+use crate::process::signal;
+use crate::process::state::{RunState, ServiceState};
+
+/// How long a stopped service gets to exit after SIGTERM before it's sent
+/// SIGKILL instead.
+const TERMINATE_GRACE: Duration = Duration::from_secs(5);
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Args, Clone)]
+pub struct StopArgs {
+    /// Project name
+    pub name: String,
+
+    /// Only stop specific services
+    #[arg(long, value_delimiter = ',')]
+    pub only: Option<Vec<String>>,
+
+    /// Skip specific services
+    #[arg(long, value_delimiter = ',')]
+    pub skip: Option<Vec<String>>,
+}
+
+impl StopArgs {
+    pub async fn execute(&self) -> Result<()> {
+        let Some(state) = RunState::load(&self.name)? else {
+            println!("No running services found for project: {}", self.name);
+            return Ok(());
+        };
+
+        let stop_order = Self::reverse_dependency_order(&state.services);
+        let by_name: HashMap<&str, &ServiceState> =
+            state.services.iter().map(|s| (s.name.as_str(), s)).collect();
+
+        let mut still_running = Vec::new();
+        for name in &stop_order {
+            let service = by_name[name.as_str()];
+
+            if !self.should_stop_service(&service.name) {
+                still_running.push(service.clone());
+                continue;
+            }
+
+            if let Err(e) = self.stop_service(service).await {
+                warn!("{}", e);
+                still_running.push(service.clone());
+            }
+        }
+
+        if still_running.is_empty() {
+            RunState::clear(&self.name)?;
+        } else {
+            RunState::save(&self.name, still_running)?;
+        }
+
+        Ok(())
+    }
+
+    fn should_stop_service(&self, name: &str) -> bool {
+        if let Some(only) = &self.only {
+            if !only.iter().any(|n| n == name) {
+                return false;
+            }
+        }
+
+        if let Some(skip) = &self.skip {
+            if skip.iter().any(|n| n == name) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Mirrors `StartArgs::resolve_start_order`'s Kahn's-algorithm resolver
+    /// over the dependency graph recorded in the run state, reversed so
+    /// dependents are stopped before what they depend on. Unlike starting,
+    /// a stale or inconsistent state file shouldn't block `stop` outright,
+    /// so a dependency naming a service not in the state is ignored, and
+    /// whatever a cycle leaves unresolved is appended in its original order
+    /// (before the reversal) rather than erroring.
+    fn reverse_dependency_order(services: &[ServiceState]) -> Vec<String> {
+        let names: HashSet<&str> = services.iter().map(|s| s.name.as_str()).collect();
+
+        let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+        let mut in_degree: HashMap<String, usize> = HashMap::new();
+        for service in services {
+            edges.entry(service.name.clone()).or_default();
+            in_degree.entry(service.name.clone()).or_insert(0);
+        }
+        for service in services {
+            for dep in &service.dependencies {
+                if !names.contains(dep.as_str()) {
+                    continue;
+                }
+                edges.get_mut(dep).unwrap().push(service.name.clone());
+                *in_degree.get_mut(&service.name).unwrap() += 1;
+            }
+        }
+
+        let mut queue: VecDeque<String> = services
+            .iter()
+            .map(|s| s.name.clone())
+            .filter(|name| in_degree[name] == 0)
+            .collect();
+
+        let mut order = Vec::with_capacity(services.len());
+        while let Some(name) = queue.pop_front() {
+            for dependent in &edges[&name] {
+                let degree = in_degree.get_mut(dependent).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(dependent.clone());
+                }
+            }
+            order.push(name);
+        }
+
+        // Anything left over (a cycle, in practice) still needs stopping;
+        // append it in its original order rather than dropping it.
+        let ordered: HashSet<&str> = order.iter().map(|s| s.as_str()).collect();
+        for service in services {
+            if !ordered.contains(service.name.as_str()) {
+                order.push(service.name.clone());
+            }
+        }
+
+        order.reverse();
+        order
+    }
+
+    async fn stop_service(&self, service: &ServiceState) -> Result<()> {
+        if !signal::is_alive(service.pid) {
+            info!("Service {} (pid {}) is already stopped", service.name, service.pid);
+            return Ok(());
+        }
+
+        info!("Stopping service {} (pid {})", service.name, service.pid);
+        signal::send_sigterm(service.pid)?;
+
+        let deadline = Instant::now() + TERMINATE_GRACE;
+        while Instant::now() < deadline {
+            if !signal::is_alive(service.pid) {
+                info!("Service {} stopped", service.name);
+                return Ok(());
+            }
+            sleep(POLL_INTERVAL).await;
+        }
+
+        warn!(
+            "Service {} did not stop within {:?} of SIGTERM, sending SIGKILL",
+            service.name, TERMINATE_GRACE
+        );
+        signal::send_sigkill(service.pid)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, ToolError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectConfig {
+    pub name: String,
+    pub description: Option<String>,
+    pub commands: Commands,
+    pub environment: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Commands {
+    pub start: StartCommands,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartCommands {
+    pub dev: String,
+    pub build: String,
+    pub services: Option<ServicesList>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServicesList {
+    pub services: Vec<Service>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Service {
+    pub name: String,
+    pub command: String,
+    pub working_dir: Option<String>,
+    /// Names of services that must be running before this one is started.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    #[serde(default)]
+    pub restart: RestartPolicy,
+    /// Base delay (seconds) before the first restart attempt; doubled on
+    /// each consecutive failure, capped at `max_delay_secs`.
+    #[serde(default = "default_restart_delay")]
+    pub restart_delay: u64,
+    /// Upper bound (seconds) on the exponential restart backoff.
+    #[serde(default = "default_max_delay_secs")]
+    pub max_delay_secs: u64,
+    /// Consecutive failures allowed before the supervisor gives up on this
+    /// service entirely.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Hostnames this service is allowed to start on; empty means no
+    /// restriction. Lets one committed config describe a stack where a
+    /// heavy service only runs on a dev workstation.
+    #[serde(default)]
+    pub hosts: Vec<String>,
+}
+
+/// How the supervisor reacts when a spawned service's process exits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    #[default]
+    No,
+}
+
+fn default_restart_delay() -> u64 {
+    1
+}
+
+fn default_max_delay_secs() -> u64 {
+    30
+}
+
+fn default_max_retries() -> u32 {
+    5
+}
+
+impl ProjectConfig {
+    pub fn from_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            ToolError::ConfigError(format!("Failed to read config file {}: {}", path, e))
+        })?;
+
+        let config: ProjectConfig = serde_yaml::from_str(&content)?;
+        Ok(config)
+    }
+}
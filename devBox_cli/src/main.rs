@@ -2,19 +2,20 @@ use crate::error::Result;
 mod error;
 mod cli;
 mod config;
-use crate::cli::{Cli, Commands, start};
+mod process;
+use crate::cli::{Cli, Commands};
 use clap::Parser;
-use log::{error, warn, info, debug, trace};
-
 
 #[tokio::main]
-async fn main() -> crate::error::Result<()> {
+async fn main() -> Result<()> {
     env_logger::init();
     let cli = Cli::parse();
-    
-    // match cli.command {
-    //     Commands::Start(args) => args.handle().await?,
-    // } 
-    // TODO: fix error there
+
+    match cli.command {
+        Some(Commands::Start(args)) => args.handle().await?,
+        Some(Commands::Stop(args)) => args.execute().await?,
+        None => println!("Run `devbox start <project>` to start a project's services"),
+    }
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod signal;
+pub mod state;
@@ -0,0 +1,62 @@
+//! Cross-platform process signaling. On Unix this talks to the kernel
+//! directly via `nix` rather than shelling out to `kill` for every call.
+//! Windows has no POSIX signals, so it gets its own termination path.
+
+#[cfg(unix)]
+mod imp {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid;
+
+    use crate::error::{Result, ToolError};
+
+    pub fn send_sigterm(pid: u32) -> Result<()> {
+        kill(Pid::from_raw(pid as i32), Signal::SIGTERM)
+            .map_err(|e| ToolError::ProcessError(format!("failed to send SIGTERM to PID {}: {}", pid, e)))
+    }
+
+    pub fn send_sigkill(pid: u32) -> Result<()> {
+        kill(Pid::from_raw(pid as i32), Signal::SIGKILL)
+            .map_err(|e| ToolError::ProcessError(format!("failed to send SIGKILL to PID {}: {}", pid, e)))
+    }
+
+    /// A signal-0 probe: doesn't actually signal the process, just checks
+    /// whether it (or a process we have permission to see) still exists.
+    pub fn is_alive(pid: u32) -> bool {
+        kill(Pid::from_raw(pid as i32), None).is_ok()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use crate::error::{Result, ToolError};
+
+    pub fn send_sigterm(pid: u32) -> Result<()> {
+        // Windows has no graceful-termination signal equivalent to SIGTERM;
+        // the best we can do without a process handle is go straight to a
+        // forceful kill.
+        send_sigkill(pid)
+    }
+
+    pub fn send_sigkill(pid: u32) -> Result<()> {
+        let output = std::process::Command::new("taskkill")
+            .args(["/PID", &pid.to_string(), "/F"])
+            .output()
+            .map_err(|e| ToolError::ProcessError(format!("failed to kill PID {}: {}", pid, e)))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(ToolError::ProcessError(format!("taskkill failed for PID {}", pid)))
+        }
+    }
+
+    pub fn is_alive(pid: u32) -> bool {
+        std::process::Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid)])
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+            .unwrap_or(false)
+    }
+}
+
+pub use imp::{is_alive, send_sigkill, send_sigterm};
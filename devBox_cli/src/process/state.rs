@@ -0,0 +1,76 @@
+//! Persistent run-state for background projects, recorded under
+//! `.devbox/<project>.state.json` when services launch so a later `devbox
+//! stop` can find and terminate what an earlier `devbox start` started -
+//! `tokio::spawn`ing a background task and dropping its join handle leaves
+//! no other way to find those processes again.
+
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, ToolError};
+
+/// One tracked service's run state: enough to both find it again (`pid`)
+/// and to stop a whole project in the right order (`dependencies`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceState {
+    pub name: String,
+    pub pid: u32,
+    pub command: String,
+    pub start_time: SystemTime,
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RunState {
+    pub services: Vec<ServiceState>,
+}
+
+impl RunState {
+    fn path_for(project_name: &str) -> PathBuf {
+        PathBuf::from(".devbox").join(format!("{}.state.json", project_name))
+    }
+
+    /// Writes `services` as the current run-state for `project_name`,
+    /// overwriting whatever was recorded by a previous `start`.
+    pub fn save(project_name: &str, services: Vec<ServiceState>) -> Result<()> {
+        let path = Self::path_for(project_name);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let json = serde_json::to_string_pretty(&RunState { services })
+            .map_err(|e| ToolError::ConfigError(format!("failed to serialize run state: {}", e)))?;
+        std::fs::write(&path, json)?;
+
+        Ok(())
+    }
+
+    /// Loads the run-state previously saved for `project_name`, or `None`
+    /// if nothing has been started (or it's already been fully stopped).
+    pub fn load(project_name: &str) -> Result<Option<Self>> {
+        let path = Self::path_for(project_name);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path)?;
+        let state: RunState = serde_json::from_str(&content).map_err(|e| {
+            ToolError::ConfigError(format!("failed to parse run state {}: {}", path.display(), e))
+        })?;
+
+        Ok(Some(state))
+    }
+
+    /// Removes the run-state file for `project_name` once every service it
+    /// recorded has been stopped.
+    pub fn clear(project_name: &str) -> Result<()> {
+        let path = Self::path_for(project_name);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
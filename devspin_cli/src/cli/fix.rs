@@ -0,0 +1,203 @@
+//! `devspin fix`: a `cargo fix`-style subcommand that finds machine-applicable
+//! corrections for a project and, with `--apply`, writes them back rather
+//! than just rejecting the config outright the way `devspin start` does.
+
+use std::collections::HashSet;
+
+use clap::Args;
+use colored::*;
+
+use crate::configs::suggest::closest_match;
+use crate::configs::yaml_parser::ProjectConfig;
+use crate::error::{Result, ToolError};
+
+#[derive(Debug, Args, Clone)]
+pub struct FixArgs {
+    /// Project to inspect/fix
+    pub name: String,
+
+    /// Write the corrections back to devspin.yaml (a `.bak` backup is made first)
+    #[arg(long)]
+    pub apply: bool,
+
+    /// Apply fixes even if the config still fails validation afterward
+    #[arg(long)]
+    pub broken_code: bool,
+
+    /// Service filter to reconcile, same as `devspin start --only`
+    #[arg(long, value_delimiter = ',')]
+    pub only: Option<Vec<String>>,
+
+    /// Service filter to reconcile, same as `devspin start --skip`
+    #[arg(long, value_delimiter = ',')]
+    pub skip: Option<Vec<String>>,
+}
+
+/// One machine-applicable correction found while inspecting a project.
+#[derive(Debug, Clone)]
+struct Fix {
+    description: String,
+    /// Whether this fix actually changes what gets written to the project
+    /// file. `--only`/`--skip` conflicts and blank filter entries are about
+    /// how this invocation was run, not the file on disk, so `execute` reads
+    /// this to exclude them from the applied count and from whether
+    /// `--apply` writes anything - otherwise they'd report as "applied" on
+    /// every run yet never go away, since there's nothing in the file for
+    /// them to fix.
+    persisted: bool,
+}
+
+impl FixArgs {
+    pub async fn execute(&self) -> Result<()> {
+        let path = format!("{}/devspin.yaml", self.name);
+        if !std::path::Path::new(&path).exists() {
+            return Err(ToolError::ConfigError(format!(
+                "Project '{}' not found at: {}", self.name, path
+            )));
+        }
+
+        println!("{}", format!("Checking project: {}", self.name).bold());
+
+        let raw = std::fs::read_to_string(&path)?;
+        let mut config: ProjectConfig = serde_yaml::from_str(&raw)?;
+
+        let mut fixes = Vec::new();
+        let mut dependency_edits = Vec::new();
+        self.reconcile_filters(&mut fixes);
+        self.fix_dependency_typos(&mut config, &mut fixes, &mut dependency_edits);
+
+        if fixes.is_empty() {
+            println!("{} {}", "✓".green(), "No fixable issues found".green());
+            return Ok(());
+        }
+
+        println!("{}", "Found fixable issues:".yellow().bold());
+        for fix in &fixes {
+            println!("  {} {}", "-".dimmed(), fix.description);
+        }
+
+        if !self.apply {
+            println!();
+            println!("{}", "Re-run with --apply to write these changes".dimmed());
+            return Ok(());
+        }
+
+        let persisted_count = fixes.iter().filter(|f| f.persisted).count();
+        if persisted_count == 0 {
+            println!();
+            println!("{} {}", "✓".green(), "Nothing to write to the project file".green());
+            return Ok(());
+        }
+
+        if let Err(e) = config.validate_dependencies() {
+            if !self.broken_code {
+                return Err(ToolError::ConfigError(format!(
+                    "{} (pass --broken-code to apply fixes anyway)", e
+                )));
+            }
+            println!("{} {}", "!".yellow(), format!("Config still has problems, applying anyway: {}", e).yellow());
+        }
+
+        let backup_path = format!("{}.bak", path);
+        std::fs::write(&backup_path, &raw)?;
+        println!("{} {}", "✓".green(), format!("Backup written to {}", backup_path).dimmed());
+
+        let rewritten = apply_dependency_edits(&raw, &dependency_edits);
+        std::fs::write(&path, rewritten)?;
+
+        println!(
+            "{} {}",
+            "✓".green(),
+            format!("Applied {} fix(es) to {}", persisted_count, path).bold()
+        );
+        Ok(())
+    }
+
+    /// `--only`/`--skip` here conflict the same way they do for `start`, but
+    /// rather than rejecting, keep the explicitly requested `--only` set
+    /// (it names exactly what the user wants) and drop the redundant
+    /// `--skip`; likewise drop any blank filter entries instead of treating
+    /// them as a service name that'll never match.
+    fn reconcile_filters(&self, fixes: &mut Vec<Fix>) {
+        if self.only.is_some() && self.skip.is_some() {
+            fixes.push(Fix {
+                description: "both --only and --skip given; keeping --only and ignoring --skip".to_string(),
+                persisted: false,
+            });
+        }
+
+        for (flag, filter) in [("--only", &self.only), ("--skip", &self.skip)] {
+            if let Some(filter) = filter {
+                if filter.iter().any(|s| s.trim().is_empty()) {
+                    fixes.push(Fix {
+                        description: format!("dropping empty service name(s) from {}", flag),
+                        persisted: false,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Rewrites a service's `dependencies` entries that name no real service
+    /// to the closest one that does, when the name is a plausible typo.
+    /// Besides updating `config` in memory (so the `--broken-code` gate's
+    /// revalidation sees the corrected names), records each `old -> new`
+    /// pair in `edits` so `--apply` can patch just that text in the original
+    /// file rather than re-serializing the whole document.
+    fn fix_dependency_typos(&self, config: &mut ProjectConfig, fixes: &mut Vec<Fix>, edits: &mut Vec<(String, String)>) {
+        let Some(services) = &mut config.services else {
+            return;
+        };
+
+        let names: HashSet<String> = services.iter().map(|s| s.name.clone()).collect();
+
+        for service in services.iter_mut() {
+            for dep in service.dependencies.iter_mut() {
+                if names.contains(dep.as_str()) {
+                    continue;
+                }
+
+                let candidates: Vec<&str> = names
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|n| *n != dep.as_str())
+                    .collect();
+
+                if let Some(suggestion) = closest_match(dep, candidates) {
+                    fixes.push(Fix {
+                        description: format!(
+                            "service '{}': dependency '{}' → '{}'",
+                            service.name, dep, suggestion
+                        ),
+                        persisted: true,
+                    });
+                    edits.push((dep.clone(), suggestion.to_string()));
+                    *dep = suggestion.to_string();
+                }
+            }
+        }
+    }
+}
+
+/// Patches each `old -> new` dependency rename directly into the raw YAML
+/// text, rather than writing back `serde_yaml::to_string(&config)`, which
+/// would silently drop every comment and reformat the whole file. Matches
+/// the first `- old` list entry (bare, single- or double-quoted) still
+/// present in the text for each edit, so edits don't clobber each other when
+/// two services happen to share a typo'd dependency name.
+fn apply_dependency_edits(raw: &str, edits: &[(String, String)]) -> String {
+    let mut text = raw.to_string();
+
+    for (old, new) in edits {
+        for quote in ["", "\"", "'"] {
+            let needle = format!("- {quote}{old}{quote}");
+            if let Some(pos) = text.find(&needle) {
+                let replacement = format!("- {quote}{new}{quote}");
+                text.replace_range(pos..pos + needle.len(), &replacement);
+                break;
+            }
+        }
+    }
+
+    text
+}
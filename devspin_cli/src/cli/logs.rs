@@ -0,0 +1,155 @@
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use clap::Args;
+use colored::*;
+use tokio::time::sleep;
+
+use crate::error::{Result, ToolError};
+use crate::process::manager::ProcessManager;
+use crate::ProcessInfo;
+
+#[derive(Debug, Args, Clone)]
+pub struct LogsArgs {
+    /// Project name
+    pub project_name: String,
+
+    /// Show logs for specific services only
+    #[arg(long, value_delimiter = ',')]
+    pub only: Option<Vec<String>>,
+
+    /// Skip specific services
+    #[arg(long, value_delimiter = ',')]
+    pub skip: Option<Vec<String>>,
+
+    /// Follow log output as it's written
+    #[arg(short, long)]
+    pub follow: bool,
+
+    /// Number of trailing lines to show per service
+    #[arg(long, default_value = "50")]
+    pub lines: usize,
+}
+
+impl LogsArgs {
+    pub async fn execute(&self) -> Result<()> {
+        self.validate_args()?;
+
+        let services = self.services_to_show();
+
+        if services.is_empty() {
+            println!("{}", "No running services found matching filters".yellow());
+            return Ok(());
+        }
+
+        for service in &services {
+            self.print_tail(service)?;
+        }
+
+        if self.follow {
+            self.follow_logs(&services).await?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_args(&self) -> Result<()> {
+        if self.only.is_some() && self.skip.is_some() {
+            return Err(ToolError::ConfigError(
+                "Cannot use both --only and --skip".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn services_to_show(&self) -> Vec<ProcessInfo> {
+        ProcessManager::get_running_services()
+            .into_iter()
+            .filter(|service| service.project_name == self.project_name)
+            .filter(|service| self.should_show_service(service))
+            .collect()
+    }
+
+    fn should_show_service(&self, service: &ProcessInfo) -> bool {
+        if let Some(only_services) = &self.only {
+            if !only_services.contains(&service.service_name) {
+                return false;
+            }
+        }
+
+        if let Some(skip_services) = &self.skip {
+            if skip_services.contains(&service.service_name) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn print_tail(&self, service: &ProcessInfo) -> Result<()> {
+        println!("{}", format!("==> {} <==", service.service_name).cyan().bold());
+
+        let Some(log_path) = &service.log_path else {
+            println!("{}", "  (no log file recorded for this service)".yellow());
+            return Ok(());
+        };
+
+        let contents = std::fs::read_to_string(log_path).map_err(|e| {
+            ToolError::IoError(format!("failed to read log file {}: {}", log_path.display(), e))
+        })?;
+
+        let lines: Vec<&str> = contents.lines().collect();
+        let start = lines.len().saturating_sub(self.lines);
+
+        for line in &lines[start..] {
+            println!("{}", line);
+        }
+
+        println!();
+        Ok(())
+    }
+
+    async fn follow_logs(&self, services: &[ProcessInfo]) -> Result<()> {
+        println!("{}", "Following logs (Ctrl+C to stop)...".dimmed());
+
+        let mut cursors: Vec<(ProcessInfo, u64)> = Vec::new();
+        for service in services {
+            if let Some(log_path) = &service.log_path {
+                let len = std::fs::metadata(log_path).map(|m| m.len()).unwrap_or(0);
+                cursors.push((service.clone(), len));
+            }
+        }
+
+        loop {
+            for (service, position) in &mut cursors {
+                let Some(log_path) = &service.log_path else { continue };
+
+                let mut file = match File::open(log_path) {
+                    Ok(file) => file,
+                    Err(_) => continue,
+                };
+
+                let len = file.metadata().map(|m| m.len()).unwrap_or(*position);
+                if len < *position {
+                    // Log file was truncated or rotated; start over from the beginning.
+                    *position = 0;
+                }
+
+                file.seek(SeekFrom::Start(*position))?;
+
+                let mut buf = String::new();
+                file.read_to_string(&mut buf)?;
+
+                for line in buf.lines() {
+                    println!("{} {}", format!("[{}]", service.service_name).cyan(), line);
+                }
+
+                *position = len;
+            }
+
+            sleep(Duration::from_millis(500)).await;
+        }
+    }
+}
@@ -0,0 +1,35 @@
+pub mod fix;
+pub mod logs;
+pub mod start;
+pub mod status;
+pub mod stop;
+pub mod welcome_message;
+
+use clap::{Parser, Subcommand};
+
+use fix::FixArgs;
+use logs::LogsArgs;
+use start::StartArgs;
+use status::StatusArgs;
+use stop::StopArgs;
+
+#[derive(Debug, Parser)]
+#[command(name = "devspin", about = "Spin up and supervise your local dev stack")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// Start a project's services
+    Start(StartArgs),
+    /// Stop a project's services
+    Stop(StopArgs),
+    /// Show the status of running services
+    Status(StatusArgs),
+    /// Show captured log output for a project's services
+    Logs(LogsArgs),
+    /// Find and apply machine-applicable corrections to a project's config
+    Fix(FixArgs),
+}
@@ -1,12 +1,74 @@
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 use colored::*;
+use serde::Serialize;
 use crate::error::{Result, ToolError};
-use crate::configs::yaml_parser::{ProjectConfig, Service};
+use crate::configs::suggest::closest_match;
+use crate::configs::yaml_parser::{is_container_service_type, ProjectConfig, Service};
+use crate::process::container::ContainerSpec;
 use crate::process::global::get_global_state;
 use crate::process::state::ProcessState;
-use log::debug; 
+use log::{debug, warn};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Semaphore;
+
+/// Output format for validation/health-check errors: `human` (default,
+/// colored text) keeps today's behavior, `json`/`short` are meant for CI and
+/// wrapper scripts to parse instead of scraping colored text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MessageFormat {
+    Human,
+    Json,
+    Short,
+}
+
+/// Stable JSON shape for a reported error, so tooling doesn't have to parse
+/// colored human-readable text.
+#[derive(Debug, Serialize)]
+struct Diagnostic {
+    level: &'static str,
+    code: &'static str,
+    service: Option<String>,
+    message: String,
+    suggestion: Option<String>,
+}
+
+impl Diagnostic {
+    fn from_error(error: &ToolError) -> Self {
+        let (code, message) = match error {
+            ToolError::ConfigError(msg) => ("config_error", msg.clone()),
+            ToolError::ProcessError(msg) => ("process_error", msg.clone()),
+            ToolError::IoError(msg) => ("io_error", msg.clone()),
+        };
+
+        // Takes the first single-quoted name in the message as `service` and,
+        // for "did you mean" suggestions, the second as `suggestion`, instead
+        // of threading extra structured fields through every ToolError call
+        // site. Messages that name a service without quotes get no `service`;
+        // the project-not-found message quotes the project name, which then
+        // lands in `service`.
+        let mut quoted = message.split('\'').skip(1).step_by(2);
+        let service = quoted.next().map(str::to_string);
+        let suggestion = message.contains("did you mean").then(|| quoted.next()).flatten().map(str::to_string);
+
+        Self { level: "error", code, service, message, suggestion }
+    }
+}
+
+const DEFAULT_HEALTH_CHECK_RETRIES: u32 = 10;
+const DEFAULT_HEALTH_CHECK_TIMEOUT_SECS: u64 = 10;
+/// Ceiling on the exponential backoff between startup-gate probe attempts.
+const MAX_PROBE_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Delay before the next startup-gate probe attempt: doubles every attempt
+/// starting from `health_check.interval_ms`, capped at `MAX_PROBE_BACKOFF`.
+fn probe_backoff_delay(base_ms: u64, attempt: u32) -> Duration {
+    Duration::from_millis(base_ms.saturating_mul(2u64.saturating_pow(attempt))).min(MAX_PROBE_BACKOFF)
+}
 
 #[derive(Debug, Args, Clone)]
 pub struct StartArgs {
@@ -35,12 +97,63 @@ pub struct StartArgs {
 
     /// Skip specific services
     #[arg(long, value_delimiter = ',')]
-    pub skip: Option<Vec<String>>
+    pub skip: Option<Vec<String>>,
+
+    /// Keep running and restart services when their source files change
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Max number of startup health checks to run concurrently
+    #[arg(long, default_value = "4")]
+    pub jobs: NonZeroUsize,
+
+    /// Output format for validation/health-check errors
+    #[arg(long, value_enum, default_value = "human")]
+    pub message_format: MessageFormat,
 }
 
 #[allow(clippy::await_holding_lock)]
 impl StartArgs {
     pub async fn execute(&self) -> Result<()> {
+        let result = self.run().await;
+
+        // `ToolError` messages are kept plain (no ANSI) so the json/short
+        // diagnostic payload below is clean; human mode is the one place
+        // that colors them, done here rather than left to the default
+        // Debug-based printer a bare `?` in `main` would fall back to.
+        if let Err(e) = &result {
+            match self.message_format {
+                MessageFormat::Human => {
+                    eprintln!("{} {}", "ERROR:".red(), e);
+                    std::process::exit(1);
+                }
+                MessageFormat::Json | MessageFormat::Short => {
+                    self.report_error(e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn report_error(&self, error: &ToolError) {
+        let diagnostic = Diagnostic::from_error(error);
+
+        match self.message_format {
+            MessageFormat::Json => {
+                if let Ok(json) = serde_json::to_string(&diagnostic) {
+                    eprintln!("{}", json);
+                }
+            }
+            MessageFormat::Short => {
+                eprintln!("[{}] {}", diagnostic.code, diagnostic.message);
+            }
+            MessageFormat::Human => unreachable!("only called for json/short formats"),
+        }
+    }
+
+    async fn run(&self) -> Result<()> {
         println!("{}", format!("Starting project: {}", self.name).bold());
 
         self.validate_args()?;
@@ -52,11 +165,24 @@ impl StartArgs {
             )))
         }
         let project = self.load_project(&default_path).await?;
+        self.validate_service_names(&project)?;
 
         if self.dry_run {
             return self.dry_run(&project);
         }
 
+        // Watch every tracked process so crashed services can be restarted
+        // according to their configured restart policy.
+        crate::process::supervisor::spawn_supervisor();
+
+        // Stop lazy services again once they've gone idle.
+        crate::process::reaper::spawn_idle_reaper();
+
+        // Keep ProcessStatus in sync with real health-check results, and
+        // top services back up to their configured min_instances.
+        crate::process::health::spawn_health_monitor();
+        crate::process::enforcer::spawn_instance_enforcer();
+
         // Load environment file if specified
         if let Some(env) = &self.env {
             println!("{}", format!("Loading environment from: {}", env).dimmed());
@@ -88,8 +214,19 @@ impl StartArgs {
         }
 
         // For foreground mode, use global state directly
-        let mut process_state: std::sync::MutexGuard<'static, ProcessState> = get_global_state();
-        self.start_services(&project, &mut process_state).await
+        {
+            let mut process_state: std::sync::MutexGuard<'static, ProcessState> = get_global_state();
+            self.start_services(&project, &mut process_state).await?;
+        }
+
+        if self.watch {
+            println!("{}", "Watching for file changes (Ctrl+C to stop)...".cyan().bold());
+            let services = project.services.clone().unwrap_or_default();
+            crate::process::watcher::spawn_watcher(project, services);
+            let _ = tokio::signal::ctrl_c().await;
+        }
+
+        Ok(())
     }
 
     async fn load_project(&self, path: &str) -> Result<ProjectConfig> {
@@ -285,36 +422,253 @@ impl StartArgs {
     }
 
     async fn spawn_service_command(
-        &self, 
-        service: &Service, 
+        &self,
+        service: &Service,
         env_vars: &HashMap<String, String>,
-        working_dir: &str
-    ) -> Result<std::process::Child> {
+        working_dir: &str,
+        project_name: &str,
+    ) -> Result<(std::process::Child, std::path::PathBuf)> {
         let mut command = std::process::Command::new("sh");
         command.arg("-c").arg(&service.command);
-        
+
         // Use the resolved working directory
         command.current_dir(working_dir);
-        
+
         for (key, value) in env_vars {
             command.env(key, value);
         }
-        
+
+        let (log_path, stdout, stderr) =
+            crate::process::logging::open_service_log(project_name, &service.name)?;
+        command.stdout(stdout);
+        command.stderr(stderr);
+
         if self.verbose {
             debug!("Spawning command: sh -c '{}' in directory: {}", service.command, working_dir);
+            debug!("Logging output to: {}", log_path.display());
         }
-        
+
         let child = command.spawn()?;
-        Ok(child)
+        Ok((child, log_path))
+    }
+
+    /// `spawn_service_command`'s counterpart for `type: docker`/`type:
+    /// compose` services: creates and starts a container through the Docker
+    /// API instead of shelling out, and tracks it the same way so dependency
+    /// ordering and health checks don't need to care which kind it is.
+    async fn start_container_service(
+        &self,
+        service: &Service,
+        project_name: &str,
+        process_state: &mut ProcessState,
+    ) -> Result<()> {
+        let image = service.image.as_deref().ok_or_else(|| {
+            ToolError::ConfigError(format!(
+                "service '{}' has type '{}' but no 'image' configured",
+                service.name, service.service_type
+            ))
+        })?;
+
+        if self.verbose {
+            println!("  {} {}", "Image:".dimmed(), image.cyan());
+            if let Some(ports) = &service.ports {
+                println!("  {} {:?}", "Ports:".dimmed(), ports);
+            }
+        }
+
+        let env = service.env.clone().unwrap_or_default();
+        let ports = service.ports.clone().unwrap_or_default();
+        let volumes = service.volumes.clone().unwrap_or_default();
+        let command = (!service.command.is_empty()).then_some(service.command.as_str());
+        let container_name = format!("devspin-{}-{}", project_name, service.name);
+
+        let container_id = crate::process::container::start_container(ContainerSpec {
+            container_name: &container_name,
+            image,
+            command,
+            ports: &ports,
+            volumes: &volumes,
+            env: &env,
+        })
+        .await
+        .map_err(|e| {
+            ToolError::ProcessError(format!("failed to start container service {}: {}", service.name, e))
+        })?;
+
+        let short_id = container_id[..container_id.len().min(12)].to_string();
+        process_state.add_container_process(container_id, service, project_name, None)?;
+
+        println!("{} {} {} {}",
+            "✓".green(),
+            format!("Started service: {}", service.name).bold(),
+            format!("(container: {})", short_id).dimmed(),
+            format!("image: {}", image).blue()
+        );
+
+        if let Some(health_check) = &service.health_check {
+            crate::process::health_cache::invalidate(project_name, &service.name);
+            Self::wait_for_health_check(service, health_check, project_name).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Binds `service.listen` on the service's behalf and spawns the real
+    /// process only on the first incoming connection, proxying the stream
+    /// through once it's reachable. Runs for the lifetime of the program.
+    fn start_lazy_service(
+        &self,
+        service: Service,
+        env_vars: HashMap<String, String>,
+        working_dir: String,
+        project_name: String,
+    ) -> Result<()> {
+        let listen_addr = service.listen.clone().ok_or_else(|| {
+            ToolError::ConfigError(format!(
+                "service '{}' is marked lazy but has no `listen` address configured",
+                service.name
+            ))
+        })?;
+
+        let backend_port = service
+            .health_check
+            .as_ref()
+            .and_then(|h| h.port)
+            .ok_or_else(|| {
+                ToolError::ConfigError(format!(
+                    "lazy service '{}' needs a port-based health_check so DevSpin knows where to proxy to",
+                    service.name
+                ))
+            })?;
+
+        println!(
+            "{} {} {}",
+            "💤".dimmed(),
+            format!("Service '{}' is lazy, listening on", service.name).dimmed(),
+            listen_addr.cyan()
+        );
+
+        tokio::spawn(async move {
+            let listener = match TcpListener::bind(&listen_addr).await {
+                Ok(listener) => listener,
+                Err(e) => {
+                    eprintln!("{} {}", "ERROR:".red(), format!(
+                        "failed to bind lazy listener for {} on {}: {}", service.name, listen_addr, e
+                    ));
+                    return;
+                }
+            };
+
+            loop {
+                let inbound = match listener.accept().await {
+                    Ok((stream, _addr)) => stream,
+                    Err(e) => {
+                        warn!("lazy listener for {} failed to accept: {}", service.name, e);
+                        continue;
+                    }
+                };
+
+                if let Err(e) = Self::handle_lazy_connection(
+                    &service,
+                    &env_vars,
+                    &working_dir,
+                    &project_name,
+                    backend_port,
+                    inbound,
+                )
+                .await
+                {
+                    eprintln!("{} {}", "ERROR:".red(), format!("lazy proxy for {} failed: {}", service.name, e));
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    async fn handle_lazy_connection(
+        service: &Service,
+        env_vars: &HashMap<String, String>,
+        working_dir: &str,
+        project_name: &str,
+        backend_port: u16,
+        mut inbound: TcpStream,
+    ) -> Result<()> {
+        let already_running = {
+            let state = get_global_state();
+            state.is_service_running(project_name, &service.name)
+        };
+
+        if !already_running {
+            println!("{}", format!("First connection to {} — starting on demand...", service.name).bold());
+
+            let mut command = std::process::Command::new("sh");
+            command.arg("-c").arg(&service.command);
+            command.current_dir(working_dir);
+            for (key, value) in env_vars {
+                command.env(key, value);
+            }
+
+            let (log_path, stdout, stderr) =
+                crate::process::logging::open_service_log(project_name, &service.name)?;
+            command.stdout(stdout);
+            command.stderr(stderr);
+
+            let child = command.spawn().map_err(|e| {
+                ToolError::ProcessError(format!("failed to start lazy service {}: {}", service.name, e))
+            })?;
+
+            {
+                let mut state = get_global_state();
+                state.add_process(child, service, project_name, Some(working_dir.to_string()), Some(log_path))?;
+            }
+
+            Self::wait_for_backend_ready(backend_port).await?;
+
+            if let Some(health_check) = &service.health_check {
+                crate::process::health_cache::invalidate(project_name, &service.name);
+                Self::wait_for_health_check(service, health_check, project_name).await?;
+            }
+        }
+
+        {
+            let mut state = get_global_state();
+            state.touch_last_active(project_name, &service.name);
+        }
+
+        let mut outbound = TcpStream::connect(("127.0.0.1", backend_port))
+            .await
+            .map_err(|e| ToolError::ProcessError(format!("failed to connect to {} backend: {}", service.name, e)))?;
+
+        tokio::io::copy_bidirectional(&mut inbound, &mut outbound)
+            .await
+            .map_err(|e| ToolError::ProcessError(format!("proxy error for {}: {}", service.name, e)))?;
+
+        Ok(())
+    }
+
+    async fn wait_for_backend_ready(port: u16) -> Result<()> {
+        for _ in 0..50 {
+            if TcpStream::connect(("127.0.0.1", port)).await.is_ok() {
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+
+        Err(ToolError::ProcessError(format!(
+            "backend on port {} never became reachable",
+            port
+        )))
     }
 
     async fn start_services(&self, project: &ProjectConfig, process_state: &mut ProcessState) -> Result<()> {
         let env_vars = project.environment.clone().unwrap_or_default();
-        
+        let mut pending_checks: Vec<(Service, crate::configs::yaml_parser::HealthCheck)> = Vec::new();
+
         if let Some(services) = &project.services {
             println!("{}", "Starting services...".cyan());
 
-            let sorted_services = self.sort_services_by_dependencies(services);
+            let sorted_services = self.sort_services_by_dependencies(services)?;
             
             if self.verbose {
                 println!("  {} services in dependency order:", "Starting".green());
@@ -336,7 +690,7 @@ impl StartArgs {
                         println!("{}", "─".repeat(50).dimmed());
                     }
                     
-                    self.wait_for_dependencies(service, &*process_state, &project.name).await?;
+                    self.wait_for_dependencies(service, services, &*process_state, &project.name).await?;
 
                     println!("{}", format!("Starting service: {}", service.name).bold());
                     
@@ -374,22 +728,53 @@ impl StartArgs {
                             .unwrap_or_else(|| ".".to_string())
                     };
                     
-                    let child = self.spawn_service_command(service, &env_vars, &working_dir).await?;
+                    if is_container_service_type(&service.service_type) {
+                        self.start_container_service(service, &project.name, process_state).await?;
+
+                        if self.verbose {
+                            println!("  {} {}", "Status:".dimmed(), "RUNNING (container)".green());
+                            println!();
+                        }
+
+                        continue;
+                    }
+
+                    if service.lazy {
+                        self.start_lazy_service(service.clone(), env_vars.clone(), working_dir.clone(), project.name.clone())?;
+
+                        if self.verbose {
+                            println!("  {} {}", "Status:".dimmed(), "LAZY (waiting for first connection)".yellow());
+                            println!();
+                        }
+
+                        continue;
+                    }
+
+                    let (child, log_path) = self
+                        .spawn_service_command(service, &env_vars, &working_dir, &project.name)
+                        .await?;
                     let pid = child.id();
 
-                    process_state.add_process(child, &service.name, &project.name, &service.command)?;
-                    
-                    println!("{} {} {} {}", 
-                        "✓".green(), 
+                    process_state.add_process(
+                        child,
+                        service,
+                        &project.name,
+                        Some(working_dir.clone()),
+                        Some(log_path),
+                    )?;
+
+                    println!("{} {} {} {}",
+                        "✓".green(),
                         format!("Started service: {}", service.name).bold(),
                         format!("(PID: {})", pid).dimmed(),
                         format!("in directory: {}", working_dir).blue()
                     );
 
                     if let Some(health_check) = &service.health_check {
-                        self.wait_for_health_check(service, health_check).await?;
+                        crate::process::health_cache::invalidate(&project.name, &service.name);
+                        pending_checks.push((service.clone(), health_check.clone()));
                     }
-                    
+
                     if self.verbose {
                         println!("  {} {}", "Status:".dimmed(), "RUNNING".green());
                         println!();
@@ -400,14 +785,63 @@ impl StartArgs {
                 }
             }
         }
-        
+
+        if !pending_checks.is_empty() {
+            self.run_health_checks_concurrently(pending_checks, &project.name).await?;
+        }
+
         println!("{}", "─".repeat(50).dimmed());
         println!("{}", "All services started successfully!".green().bold());
         println!("{}", format!("Tracking {} processes in memory", process_state.process_count()).dimmed());
-        
+
         Ok(())
     }
 
+    /// Runs every pending startup health check concurrently, capped at
+    /// `self.jobs` in flight at once, so a stack with many services doesn't
+    /// probe them one at a time. Every check still runs to completion even
+    /// after an earlier one fails, so `devspin start` reports every failing
+    /// service rather than bailing out on the first.
+    async fn run_health_checks_concurrently(
+        &self,
+        checks: Vec<(Service, crate::configs::yaml_parser::HealthCheck)>,
+        project_name: &str,
+    ) -> Result<()> {
+        let semaphore = Arc::new(Semaphore::new(self.jobs.get()));
+        let mut handles = Vec::with_capacity(checks.len());
+
+        for (service, health_check) in checks {
+            let semaphore = Arc::clone(&semaphore);
+            let project_name = project_name.to_string();
+            handles.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+                let result = Self::wait_for_health_check(&service, &health_check, &project_name).await;
+                (service.name, result)
+            }));
+        }
+
+        let mut failures = Vec::new();
+        for handle in handles {
+            let (service_name, result) = handle
+                .await
+                .map_err(|e| ToolError::ProcessError(format!("health check task panicked: {}", e)))?;
+
+            if let Err(e) = result {
+                failures.push(format!("{}: {}", service_name, e));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ToolError::ProcessError(format!(
+                "{} service(s) failed their startup health check:\n  {}",
+                failures.len(),
+                failures.join("\n  ")
+            )))
+        }
+    }
+
     async fn start_in_background(&self, project: ProjectConfig) -> Result<()> {
         println!("{}", format!("Starting project '{}' in background mode...", project.name).bold());
 
@@ -446,12 +880,6 @@ impl StartArgs {
         
         // Start each service and track it
         for service in services_to_start {
-            println!("{}", format!("Starting background service: {}", service.name).bold());
-            
-            if self.verbose {
-                println!("  {} {}", "Command:".dimmed(), service.command.dimmed());
-            }
-            
             // RESOLVE working directory
             let working_dir = if let Some(service_dir) = &service.working_dir {
                 project.resolve_path(service_dir).to_string_lossy().to_string()
@@ -460,13 +888,43 @@ impl StartArgs {
                     .map(|p| p.to_string_lossy().to_string())
                     .unwrap_or_else(|| ".".to_string())
             };
-            
-            match self.spawn_service_command(&service, &env_vars, &working_dir).await {
-                Ok(child) => {
+
+            if is_container_service_type(&service.service_type) {
+                if let Err(e) = self
+                    .start_container_service(&service, &project_name, &mut process_state)
+                    .await
+                {
+                    eprintln!("{} {}", "ERROR:".red(), format!("Failed to start container service {}: {}", service.name, e).red());
+                }
+                continue;
+            }
+
+            if service.lazy {
+                self.start_lazy_service(service.clone(), env_vars.clone(), working_dir, project_name.clone())?;
+                continue;
+            }
+
+            println!("{}", format!("Starting background service: {}", service.name).bold());
+
+            if self.verbose {
+                println!("  {} {}", "Command:".dimmed(), service.command.dimmed());
+            }
+
+            match self
+                .spawn_service_command(&service, &env_vars, &working_dir, &project_name)
+                .await
+            {
+                Ok((child, log_path)) => {
                     let pid = child.id();
-                    
+
                     // Add to the SAME global state instance (no race condition)
-                    match process_state.add_process(child, &service.name, &project_name, &service.command) {
+                    match process_state.add_process(
+                        child,
+                        &service,
+                        &project_name,
+                        Some(working_dir.clone()),
+                        Some(log_path),
+                    ) {
                         Ok(()) => {
                             println!("{} {} {} {}", 
                                 "✓".green(), 
@@ -502,114 +960,303 @@ impl StartArgs {
         
         Ok(())
     }
-    fn sort_services_by_dependencies<'a>(&self, services: &'a [Service]) -> Vec<&'a Service> {
-        let mut sorted = Vec::new();
-        let mut visited = std::collections::HashSet::new();
+    /// Computes a safe startup order via Kahn's algorithm: a service is
+    /// always started after everything it depends on. Dependencies naming a
+    /// service outside this set are ignored rather than treated as edges,
+    /// since there's nothing here to order them against. Mirrors
+    /// `stop.rs`'s `sort_services_for_shutdown`, which runs the same
+    /// algorithm in reverse for teardown.
+    fn sort_services_by_dependencies<'a>(&self, services: &'a [Service]) -> Result<Vec<&'a Service>> {
+        let names: std::collections::HashSet<&str> = services.iter().map(|s| s.name.as_str()).collect();
+
+        // in_degree counts how many (in-set) dependencies each service has;
+        // dependents maps a service to the services that depend on it.
+        let mut in_degree: HashMap<&str, usize> = HashMap::new();
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
 
         for service in services {
-            Self::visit_service(service, services, &mut visited, &mut sorted);
-        }
-        
-        sorted
-    }
+            in_degree.entry(service.name.as_str()).or_insert(0);
 
-    fn visit_service<'a>(
-        service: &'a Service,
-        all_services: &'a [Service],
-        visited: &mut std::collections::HashSet<&'a str>,
-        sorted: &mut Vec<&'a Service>
-    ) {
-        if visited.contains(service.name.as_str()) {
-            return;
+            for dep in &service.dependencies {
+                if !names.contains(dep.as_str()) {
+                    continue;
+                }
+
+                *in_degree.entry(service.name.as_str()).or_insert(0) += 1;
+                dependents.entry(dep.as_str()).or_default().push(service.name.as_str());
+            }
         }
 
-        visited.insert(service.name.as_str());
+        let mut queue: std::collections::VecDeque<&str> = in_degree
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&name, _)| name)
+            .collect();
 
-        for dep_name in &service.dependencies {
-            if let Some(dep_service) = all_services.iter().find(|s| &s.name == dep_name) {
-                Self::visit_service(dep_service, all_services, visited, sorted);
+        let mut order = Vec::with_capacity(services.len());
+
+        while let Some(name) = queue.pop_front() {
+            order.push(name);
+
+            if let Some(deps) = dependents.get(name) {
+                for &dependent in deps {
+                    let degree = in_degree.get_mut(dependent).unwrap();
+                    *degree -= 1;
+                    if *degree == 0 {
+                        queue.push_back(dependent);
+                    }
+                }
             }
         }
 
-        sorted.push(service);
+        if order.len() != names.len() {
+            let remaining: Vec<&str> = names.into_iter().filter(|name| !order.contains(name)).collect();
+            return Err(ToolError::ConfigError(format!(
+                "dependency cycle detected among services: {}",
+                remaining.join(", ")
+            )));
+        }
+
+        let by_name: HashMap<&str, &Service> = services.iter().map(|s| (s.name.as_str(), s)).collect();
+
+        Ok(order.into_iter().map(|name| by_name[name]).collect())
     }
 
-    async fn wait_for_dependencies(&self, service: &Service, process_state: &ProcessState, project_name: &str) -> Result<()> {
+    /// Blocks `service` from starting until every dependency it declares is
+    /// actually ready — "ready" meaning the dependency's process is running
+    /// and, if it declares its own `health_check`, that it's passing that
+    /// check too. Polls rather than sleeping a fixed second, and gives up
+    /// with a `ToolError` once `depends_on_timeout` elapses for a given
+    /// dependency, so a stuck dependency can't hang `devspin start` forever.
+    ///
+    /// Takes `process_state` by reference rather than reacquiring the global
+    /// lock itself: in foreground mode the caller already holds it for the
+    /// whole of `start_services`, and `get_global_state()`'s `Mutex` isn't
+    /// reentrant, so locking again here would deadlock the same thread.
+    async fn wait_for_dependencies(
+        &self,
+        service: &Service,
+        all_services: &[Service],
+        process_state: &ProcessState,
+        project_name: &str,
+    ) -> Result<()> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
         for dep_name in &service.dependencies {
-            if !process_state.is_service_running(project_name, dep_name) {
+            let dep_service = all_services.iter().find(|s| &s.name == dep_name);
+            let deadline = Instant::now() + Duration::from_secs(service.depends_on_timeout);
+
+            loop {
+                let present = process_state.is_service_running(project_name, dep_name);
+                let ready = present
+                    && match dep_service.and_then(|s| s.health_check.as_ref()) {
+                        Some(health_check) => {
+                            Self::wait_for_health_check(dep_service.unwrap(), health_check, project_name)
+                                .await
+                                .is_ok()
+                        }
+                        None => true,
+                    };
+
+                if ready {
+                    if self.verbose {
+                        println!("  {} {}", "Dependency ready:".green(), dep_name);
+                    }
+                    break;
+                }
+
+                if Instant::now() >= deadline {
+                    return Err(ToolError::ConfigError(format!(
+                        "service '{}' timed out after {}s waiting for dependency '{}' to become ready",
+                        service.name, service.depends_on_timeout, dep_name
+                    )));
+                }
+
                 println!("{}", format!("Waiting for dependency: {} → {}", service.name, dep_name).dimmed());
                 if self.verbose {
                     println!("  {} {}", "Dependency not yet ready:".yellow(), dep_name);
                 }
-                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-            } else if self.verbose {
-                println!("  {} {}", "Dependency ready:".green(), dep_name);
+                tokio::time::sleep(POLL_INTERVAL).await;
             }
         }
         Ok(())
     }
 
-    async fn wait_for_health_check(&self, service: &Service, health_check: &crate::configs::yaml_parser::HealthCheck) -> Result<()> {
+    /// Doesn't read `self` — it's a plain function over its arguments so it
+    /// can also be driven from the lazy-activation listener, which has no
+    /// `StartArgs` to borrow from.
+    async fn wait_for_health_check(
+        service: &Service,
+        health_check: &crate::configs::yaml_parser::HealthCheck,
+        project_name: &str,
+    ) -> Result<()> {
+        if crate::process::health_cache::is_cached_healthy(project_name, service, health_check) {
+            println!("{} {}", "✓".green(), format!("Health check cached (unchanged): {}", service.name).bold());
+            return Ok(());
+        }
+
         println!("{}: {}", ("Waiting for health check").to_string().dimmed(), service.name.to_string().cyan());
 
         match health_check.type_entry.as_str() {
             "http" => {
-                self.wait_for_http_health_check(health_check).await?;
+                Self::wait_for_http_health_check(health_check).await?;
             }
             "port" => {
-                self.wait_for_port_health_check(health_check).await?;
+                Self::wait_for_port_health_check(health_check).await?;
             }
             _ => {
                 println!("{}", format_args!("Unrecognized health check type: {}", health_check.type_entry))
             }
         }
 
+        crate::process::health_cache::record_healthy(project_name, service, health_check);
+
         println!("{} {}", "✓".green(), format!("Health check passed: {}", service.name).bold());
         Ok(())
     }
 
-    async fn wait_for_http_health_check(&self, health_check: &crate::configs::yaml_parser::HealthCheck) -> Result<()> {
-        println!("   {} {}: {}", "🌐".cyan(), "HTTP check".to_string().dimmed(), health_check.http_target.to_string().cyan().bold());
-        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-        Ok(())
+    async fn wait_for_http_health_check(health_check: &crate::configs::yaml_parser::HealthCheck) -> Result<()> {
+        let target = match &health_check.path {
+            Some(path) => format!("http://127.0.0.1:{}{}", health_check.port.unwrap_or(80), path),
+            None => health_check.http_target.clone(),
+        };
+
+        println!("   {} {}: {}", "🌐".cyan(), "HTTP check".to_string().dimmed(), target.cyan().bold());
+
+        let max_retries = health_check.retries.unwrap_or(DEFAULT_HEALTH_CHECK_RETRIES);
+        let probe_timeout = Duration::from_millis(health_check.probe_timeout_ms);
+        let deadline = Instant::now()
+            + Duration::from_secs(health_check.timeout_secs.unwrap_or(DEFAULT_HEALTH_CHECK_TIMEOUT_SECS));
+
+        let mut attempts = 0u32;
+        for attempt in 0..max_retries {
+            attempts += 1;
+            let healthy = matches!(
+                tokio::time::timeout(probe_timeout, reqwest::get(&target)).await,
+                Ok(Ok(response)) if Self::http_status_matches(health_check, response.status())
+            );
+
+            if healthy {
+                return Ok(());
+            }
+
+            if Instant::now() >= deadline || attempt + 1 >= max_retries {
+                break;
+            }
+
+            tokio::time::sleep(probe_backoff_delay(health_check.interval_ms, attempt)).await;
+        }
+
+        Err(ToolError::ProcessError(format!(
+            "HTTP health check against {} did not pass within {} attempts",
+            target, attempts
+        )))
     }
 
-    async fn wait_for_port_health_check(&self, health_check: &crate::configs::yaml_parser::HealthCheck) -> Result<()> {
-        if let Some(port) = health_check.port {
-            println!("   {}", format!("Port check: {}", port).dimmed()); 
-            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    /// Whether a probed response counts as healthy: an explicit
+    /// `expected_status` must match exactly, otherwise any 2xx/3xx passes.
+    fn http_status_matches(health_check: &crate::configs::yaml_parser::HealthCheck, status: reqwest::StatusCode) -> bool {
+        match health_check.expected_status {
+            Some(expected) => status.as_u16() == expected,
+            None => status.is_success() || status.is_redirection(),
         }
-        Ok(())
+    }
+
+    async fn wait_for_port_health_check(health_check: &crate::configs::yaml_parser::HealthCheck) -> Result<()> {
+        let Some(port) = health_check.port else {
+            return Ok(());
+        };
+
+        println!("   {}", format!("Port check: {}", port).dimmed());
+
+        let max_retries = health_check.retries.unwrap_or(DEFAULT_HEALTH_CHECK_RETRIES);
+        let probe_timeout = Duration::from_millis(health_check.probe_timeout_ms);
+        let deadline = Instant::now()
+            + Duration::from_secs(health_check.timeout_secs.unwrap_or(DEFAULT_HEALTH_CHECK_TIMEOUT_SECS));
+
+        let mut attempts = 0u32;
+        for attempt in 0..max_retries {
+            attempts += 1;
+            let healthy = matches!(
+                tokio::time::timeout(probe_timeout, TcpStream::connect(("127.0.0.1", port))).await,
+                Ok(Ok(_))
+            );
+
+            if healthy {
+                return Ok(());
+            }
+
+            if Instant::now() >= deadline || attempt + 1 >= max_retries {
+                break;
+            }
+
+            tokio::time::sleep(probe_backoff_delay(health_check.interval_ms, attempt)).await;
+        }
+
+        Err(ToolError::ProcessError(format!(
+            "port health check on {} did not pass within {} attempts",
+            port, attempts
+        )))
     }
 
     fn validate_args(&self) -> Result<()> {
         if self.only.is_some() && self.skip.is_some() {
             return Err(ToolError::ConfigError(
-                format!("{} Cannot use both --only and --skip filters simultaneously", "ERROR:".red())
+                "Cannot use both --only and --skip filters simultaneously".to_string()
             ));
         }
-        
+
         // Validate service names in filters
         if let Some(only_services) = &self.only {
             for service in only_services {
                 if service.trim().is_empty() {
                     return Err(ToolError::ConfigError(
-                        format!("{} Empty service name in --only filter", "ERROR:".red())
+                        "Empty service name in --only filter".to_string()
                     ));
                 }
             }
         }
-        
+
         if let Some(skip_services) = &self.skip {
             for service in skip_services {
                 if service.trim().is_empty() {
                     return Err(ToolError::ConfigError(
-                        format!("{} Empty service name in --skip filter", "ERROR:".red())
+                        "Empty service name in --skip filter".to_string()
                     ));
                 }
             }
         }
-        
+
+        Ok(())
+    }
+
+    /// Cross-references `--only`/`--skip` entries against the services
+    /// actually defined in `project`, so a typo like `--only api-serer`
+    /// fails fast with a suggestion instead of silently matching nothing.
+    fn validate_service_names(&self, project: &ProjectConfig) -> Result<()> {
+        let Some(services) = &project.services else {
+            return Ok(());
+        };
+
+        let names: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+
+        for filter in [&self.only, &self.skip] {
+            let Some(filter) = filter else { continue };
+
+            for entry in filter {
+                if names.contains(&entry.as_str()) {
+                    continue;
+                }
+
+                let message = match closest_match(entry, names.iter().copied()) {
+                    Some(suggestion) => format!("unknown service '{}'; did you mean '{}'?", entry, suggestion),
+                    None => format!("unknown service '{}'", entry),
+                };
+
+                return Err(ToolError::ConfigError(message));
+            }
+        }
+
         Ok(())
     }
 }
\ No newline at end of file
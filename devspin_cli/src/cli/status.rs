@@ -1,5 +1,7 @@
-use clap::Args;
+use clap::{Args, ValueEnum};
 use colored::*;
+use log::warn;
+use serde::Serialize;
 use tokio::time::{interval, Duration};
 use crossterm::{
     terminal::{Clear, ClearType},
@@ -9,7 +11,28 @@ use crossterm::{
 };
 use std::io;
 use crate::ProcessInfo;
-use crate::error::Result;
+use crate::error::{Result, ToolError};
+
+/// Startup grace for a service whose `health_check` sets no `timeout_secs`:
+/// until it has been up this long it reports as starting instead of being
+/// probed. A service with no `health_check` at all reports as unknown.
+const DEFAULT_STARTUP_GRACE_SECS: u64 = 10;
+
+/// Default subject `devspin status --nats <url>` publishes snapshots to
+/// when `--nats-subject` isn't given.
+const DEFAULT_NATS_SUBJECT: &str = "devspin.status";
+
+/// Output format for `devspin status`: `human` (default) keeps today's
+/// colored, multi-line rendering; `json` serializes one full snapshot via
+/// serde so other tools can consume it without scraping text; `ndjson`
+/// does the same but prints one line per refresh, meant to be paired with
+/// `--follow` so a long-running subscriber can tail the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Human,
+    Json,
+    Ndjson,
+}
 
 #[derive(Debug, Args, Clone)]
 pub struct StatusArgs {
@@ -39,10 +62,41 @@ pub struct StatusArgs {
     /// Number of log lines to show per service
     #[arg(long, default_value = "10")]
     pub tail: usize,
+
+    /// Auto-restart services that go unhealthy, with exponential backoff
+    /// and a per-service max-retries cap
+    #[arg(long)]
+    pub supervise: bool,
+
+    /// Stop services that have had no log output or connections for their
+    /// configured `watch_idle_timeout_secs`
+    #[arg(long)]
+    pub watch_idle: bool,
+
+    /// Output format: `human` (default), `json` (one full snapshot), or
+    /// `ndjson` (one snapshot per line, meant for use with `--follow`)
+    #[arg(long, value_enum, default_value = "human")]
+    pub format: OutputFormat,
+
+    /// Publish each status snapshot to this NATS server, e.g.
+    /// `nats://localhost:4222`, so it can be watched alongside other
+    /// machines/projects from one place
+    #[arg(long)]
+    pub nats: Option<String>,
+
+    /// NATS subject to publish snapshots to (only used with `--nats`)
+    #[arg(long, default_value = DEFAULT_NATS_SUBJECT)]
+    pub nats_subject: String,
 }
 
 impl StatusArgs {
     pub async fn execute(&self) -> Result<()> {
+        match self.format {
+            OutputFormat::Json => return self.show_json_snapshot().await,
+            OutputFormat::Ndjson => return self.follow_ndjson().await,
+            OutputFormat::Human => {}
+        }
+
         if self.follow {
             self.follow_mode().await?;
         } else if self.logs {
@@ -53,12 +107,61 @@ impl StatusArgs {
         Ok(())
     }
 
+    /// Prints one `Vec<LiveServiceState>` snapshot as pretty-printed JSON,
+    /// for `devspin status --format json`.
+    async fn show_json_snapshot(&self) -> Result<()> {
+        let services = self.get_active_services().await?;
+        self.publish_if_configured(&services).await;
+
+        let json = serde_json::to_string_pretty(&services)
+            .map_err(|e| ToolError::ProcessError(format!("failed to serialize status: {}", e)))?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    /// Prints one compact JSON snapshot per line, repeating on `--interval`
+    /// while `--follow` is set (once otherwise), for `devspin status
+    /// --format ndjson`.
+    async fn follow_ndjson(&self) -> Result<()> {
+        let mut refresh_interval = interval(Duration::from_secs(self.interval));
+
+        loop {
+            let services = self.get_active_services().await?;
+            self.publish_if_configured(&services).await;
+
+            let json = serde_json::to_string(&services)
+                .map_err(|e| ToolError::ProcessError(format!("failed to serialize status: {}", e)))?;
+            println!("{}", json);
+
+            if !self.follow {
+                break;
+            }
+            refresh_interval.tick().await;
+        }
+
+        Ok(())
+    }
+
+    /// Publishes a snapshot to NATS when `--nats` is set, logging rather
+    /// than failing the whole status render if the publish itself fails
+    /// (a monitoring side-channel shouldn't take down the primary output).
+    async fn publish_if_configured(&self, services: &[LiveServiceState]) {
+        let Some(url) = &self.nats else {
+            return;
+        };
+
+        if let Err(err) = crate::process::publisher::publish(url, &self.nats_subject, services).await {
+            warn!("failed to publish status snapshot to NATS: {}", err);
+        }
+    }
+
     async fn show_current_state(&self) -> Result<()> {
         println!("{}", "CURRENT SERVICE STATES".bright_cyan().bold());
         println!("{}", "=".repeat(80).cyan());
 
         let services = self.get_active_services().await?;
-        
+        self.publish_if_configured(&services).await;
+
         if services.is_empty() {
             println!("{}", "No active services found".yellow());
             return Ok(());
@@ -121,6 +224,10 @@ impl StatusArgs {
             println!("  {}: {}", "Error".red().bold(), error.red());
         }
 
+        if self.supervise && service.restart_count > 0 {
+            println!("  {}: {}", "Supervisor".dimmed(), self.format_restart_note(service));
+        }
+
         // Recent log lines
         if self.logs && !service.recent_logs.is_empty() {
             println!("  {}:", "Recent Logs".dimmed());
@@ -147,7 +254,8 @@ impl StatusArgs {
             println!("{}", "=".repeat(80).cyan());
             
             let services = self.get_active_services().await?;
-            
+            self.publish_if_configured(&services).await;
+
             for service in &services {
                 self.print_live_service_state(service).await?;
             }
@@ -209,23 +317,58 @@ impl StatusArgs {
             }
         }
 
+        if self.supervise && service.restart_count > 0 {
+            println!("    {}", self.format_restart_note(service).dimmed());
+        }
+
         Ok(())
     }
 
+    /// One-line note on a service's `--supervise` restart history, e.g.
+    /// `restarted 2x (last 34s ago)` or, once it's exceeded its cap,
+    /// `gave up after 5 restarts`.
+    fn format_restart_note(&self, service: &LiveServiceState) -> String {
+        if service.restart_gave_up {
+            return format!("gave up after {} restarts", service.restart_count);
+        }
+
+        match service.last_restart {
+            Some(last_restart) => format!(
+                "restarted {}x (last {} ago)",
+                service.restart_count,
+                self.format_uptime(last_restart)
+            ),
+            None => format!("restarted {}x", service.restart_count),
+        }
+    }
+
     async fn show_follow_summary(&self, services: &[LiveServiceState]) -> Result<()> {
         println!();
         println!("{}", "-".repeat(40).dimmed());
-        
+
         let healthy = services.iter().filter(|s| s.health == ServiceHealth::Healthy).count();
         let total = services.len();
-        
-        println!("  {}: {}/{} services healthy", 
-            "Status".dimmed(), 
-            healthy.to_string().green(), 
+
+        println!("  {}: {}/{} services healthy",
+            "Status".dimmed(),
+            healthy.to_string().green(),
             total
         );
+
+        if self.supervise {
+            let restarted: Vec<&LiveServiceState> = services.iter().filter(|s| s.restart_count > 0).collect();
+            if restarted.is_empty() {
+                println!("  {}: none restarted", "Supervisor".dimmed());
+            } else {
+                println!("  {}:", "Supervisor".dimmed());
+                for service in restarted {
+                    println!("    • {}: {}", service.name, self.format_restart_note(service));
+                }
+            }
+        }
+
         println!("  {}: Press 'q' to exit", "Help".dimmed());
-        
+
         Ok(())
     }
 
@@ -258,63 +401,245 @@ impl StatusArgs {
 
     async fn get_active_services(&self) -> Result<Vec<LiveServiceState>> {
         use crate::process::manager::ProcessManager;
-        
-        let mut active_services = Vec::new();
-        
+
         // Get REAL services from ProcessManager
         let real_services = ProcessManager::get_running_services();
-        
-        for service in &real_services {
-            // Convert ProcessInfo to LiveServiceState
-            let live_service = self.convert_to_live_state(service);
-            
+
+        // Probe each service's actual readiness concurrently, rather than
+        // one at a time, so a slow/hung probe can't stall the whole render.
+        let mut handles = Vec::with_capacity(real_services.len());
+        for process_info in real_services {
+            handles.push(tokio::spawn(async move {
+                let health = Self::probe_health(&process_info).await;
+                (process_info, health)
+            }));
+        }
+
+        let mut active_services = Vec::with_capacity(handles.len());
+        for handle in handles {
+            let (process_info, probed_health) = handle
+                .await
+                .map_err(|e| ToolError::ProcessError(format!("health probe task panicked: {}", e)))?;
+
+            // State matchers need a resource sample regardless of whether
+            // `--resources` is displaying one, since they're a health
+            // signal rather than just a render concern.
+            let resource_sample = if self.resources || !process_info.state_matchers.is_empty() {
+                crate::process::resources::sample(process_info.pid)
+            } else {
+                None
+            };
+
+            let tripped_matcher = (!process_info.state_matchers.is_empty()).then(|| {
+                let matchers = crate::process::state_matcher::build_matchers(&process_info.state_matchers);
+                crate::process::state_matcher::tripped_matcher(
+                    process_info.pid,
+                    &matchers,
+                    resource_sample.as_ref(),
+                    &process_info,
+                )
+            }).flatten();
+
+            let health = if tripped_matcher.is_some() {
+                ServiceHealth::Unhealthy
+            } else {
+                probed_health
+            };
+
+            let (restart_record, just_restarted) = if self.supervise {
+                crate::process::health_supervisor::maybe_restart(&process_info, &health).await
+            } else {
+                (crate::process::health_supervisor::record_for(&process_info), false)
+            };
+
+            // Recent logs are read here (rather than inside
+            // `convert_to_live_state`) so the "did anything new show up"
+            // bool from `log_tail::poll` is available for the idle check
+            // below without tailing the same file twice.
+            let (recent_logs, new_log_activity) = match process_info.log_path.as_deref() {
+                Some(log_path) => crate::process::log_tail::poll(process_info.pid, log_path),
+                None => (Vec::new(), false),
+            };
+
+            let idle_timeout_hit = if self.watch_idle {
+                if new_log_activity || crate::process::resources::has_connection_activity(process_info.pid) {
+                    crate::process::activity::touch(process_info.pid);
+                }
+
+                process_info.watch_idle_timeout_secs.and_then(|timeout_secs| {
+                    let idle_for = crate::process::activity::idle_for(process_info.pid);
+                    (idle_for >= Duration::from_secs(timeout_secs)).then_some(timeout_secs)
+                })
+            } else {
+                None
+            };
+
+            let idle_stop_note = match idle_timeout_hit {
+                Some(timeout_secs) => {
+                    let stopped = crate::process::global::get_global_state().stop_for_idle(process_info.pid);
+                    // `stop_for_idle` only untracks a container; stop it here.
+                    if let Some(container_id) = stopped.and_then(|info| info.container_id) {
+                        if let Err(err) = crate::process::container::stop_container(&container_id).await {
+                            warn!("failed to stop idle container for {}: {}", process_info.service_name, err);
+                        }
+                    }
+                    Some(format!("stopped after {}s of inactivity", timeout_secs))
+                }
+                None => None,
+            };
+
+            let live_service = self.convert_to_live_state(
+                &process_info,
+                health,
+                resource_sample,
+                tripped_matcher,
+                restart_record,
+                just_restarted,
+                recent_logs,
+                idle_stop_note,
+            );
+
             // Apply filters
             if let Some(project_filter) = &self.project_name {
                 if &live_service.project != project_filter {
                     continue;
                 }
             }
-            
+
             if self.errors && live_service.health != ServiceHealth::Unhealthy {
                 continue;
             }
-            
+
             active_services.push(live_service);
         }
-        
+
         Ok(active_services)
     }
 
-    fn convert_to_live_state(&self, process_info: &ProcessInfo) -> LiveServiceState {
-        // Determine health based on actual process state
-        let health = match &process_info.status {
-            crate::ProcessStatus::Running => {
-                // You could add actual health checks here
-                // For now, assume running processes are healthy
-                ServiceHealth::Healthy
-            }
-            crate::ProcessStatus::Stopped => ServiceHealth::Unhealthy,
-            crate::ProcessStatus::Error(_) => ServiceHealth::Unhealthy,
+    /// Actively probes a service's configured `health_check` instead of
+    /// assuming a running process is automatically healthy, so `--errors`
+    /// and the unhealthy summary reflect real readiness (HTTP 200, DB ping,
+    /// etc.) rather than mere process liveness. A service still inside its
+    /// startup grace window reports `Starting` instead of `Unhealthy` so a
+    /// slow-booting process doesn't immediately look broken; a hung or
+    /// failed-to-spawn probe reports `Unknown` rather than either extreme.
+    async fn probe_health(process_info: &ProcessInfo) -> ServiceHealth {
+        if !matches!(process_info.status, crate::ProcessStatus::Running) {
+            return ServiceHealth::Unhealthy;
+        }
+
+        let Some(health_check) = &process_info.health_check else {
+            return ServiceHealth::Unknown;
         };
 
+        let grace_period = Duration::from_secs(
+            health_check.timeout_secs.unwrap_or(DEFAULT_STARTUP_GRACE_SECS),
+        );
+        if process_info.start_time.elapsed().unwrap_or_default() < grace_period {
+            return ServiceHealth::Starting;
+        }
+
+        let probe_timeout = Duration::from_millis(health_check.probe_timeout_ms);
+        match tokio::time::timeout(probe_timeout, Self::run_probe(health_check)).await {
+            Ok(Some(true)) => ServiceHealth::Healthy,
+            Ok(Some(false)) => ServiceHealth::Unhealthy,
+            Ok(None) | Err(_) => ServiceHealth::Unknown,
+        }
+    }
+
+    /// Runs one health-check attempt, returning `None` when the check can't
+    /// produce a verdict at all (unrecognized type, or missing command/port)
+    /// rather than `Some(false)`, so that case is reported as `Unknown`
+    /// instead of a false "unhealthy".
+    async fn run_probe(health_check: &crate::configs::yaml_parser::HealthCheck) -> Option<bool> {
+        match health_check.type_entry.as_str() {
+            "shell" => {
+                let command = health_check.command.as_ref()?;
+                tokio::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(command)
+                    .status()
+                    .await
+                    .ok()
+                    .map(|status| status.success())
+            }
+            "http" => {
+                let target = match &health_check.path {
+                    Some(path) => format!("http://127.0.0.1:{}{}", health_check.port?, path),
+                    None => health_check.http_target.clone(),
+                };
+
+                match reqwest::get(&target).await {
+                    Ok(response) => Some(Self::http_status_matches(health_check, response.status())),
+                    Err(_) => Some(false),
+                }
+            }
+            "port" | "tcp" => {
+                let port = health_check.port?;
+                Some(tokio::net::TcpStream::connect(("127.0.0.1", port)).await.is_ok())
+            }
+            _ => None,
+        }
+    }
+
+    /// Same pass/fail rule `devspin start`'s startup gate uses: an explicit
+    /// `expected_status` must match exactly, otherwise any 2xx/3xx counts.
+    fn http_status_matches(health_check: &crate::configs::yaml_parser::HealthCheck, status: reqwest::StatusCode) -> bool {
+        match health_check.expected_status {
+            Some(expected) => status.as_u16() == expected,
+            None => status.is_success() || status.is_redirection(),
+        }
+    }
+
+    fn convert_to_live_state(
+        &self,
+        process_info: &ProcessInfo,
+        health: ServiceHealth,
+        resource_sample: Option<ResourceUsage>,
+        tripped_matcher: Option<String>,
+        restart_record: crate::process::health_supervisor::RestartRecord,
+        just_restarted: bool,
+        recent_logs: Vec<String>,
+        idle_stop_note: Option<String>,
+    ) -> LiveServiceState {
         LiveServiceState {
             name: process_info.service_name.clone(),
             project: process_info.project_name.clone(),
             pid: process_info.pid,
-            status: match &process_info.status {
-                crate::ProcessStatus::Running => ServiceStatus::Running,
-                crate::ProcessStatus::Stopped => ServiceStatus::Stopped,
-                crate::ProcessStatus::Error(err) => ServiceStatus::Error(err.clone()),
+            // An idle auto-stop this tick takes priority over everything
+            // else: the service has already been stopped and untracked, so
+            // this is the only render that can show it as
+            // `Stopped` rather than having it silently vanish on the next
+            // poll. A restart attempted this tick comes next, so the live
+            // table shows `Restarting` rather than flashing back to
+            // `Running` for the old, now-dead pid.
+            status: if idle_stop_note.is_some() {
+                ServiceStatus::Stopped
+            } else if just_restarted {
+                ServiceStatus::Restarting
+            } else {
+                match &process_info.status {
+                    crate::ProcessStatus::Running => ServiceStatus::Running,
+                    crate::ProcessStatus::Stopped => ServiceStatus::Stopped,
+                    crate::ProcessStatus::Error(err) => ServiceStatus::Error(err.clone()),
+                }
             },
             health,
             start_time: process_info.start_time,
-            last_output: None, // You'd need to capture this from process output
-            last_error: match &process_info.status {
+            restart_count: restart_record.restart_count,
+            last_restart: restart_record.last_restart,
+            restart_gave_up: restart_record.gave_up,
+            last_output: recent_logs.last().cloned(),
+            // An idle-stop note explains an otherwise-puzzling `Stopped`
+            // status; short of that, a tripped state matcher takes priority
+            // over a plain process error, since it's the more specific,
+            // actionable explanation.
+            last_error: idle_stop_note.or(tripped_matcher).or_else(|| match &process_info.status {
                 crate::ProcessStatus::Error(err) => Some(err.clone()),
                 _ => None,
-            },
-            recent_logs: Vec::new(), // You'd need to capture process stdout/stderr
-            resource_usage: None, // You could implement this with system calls
+            }),
+            recent_logs,
+            resource_usage: if self.resources { resource_sample } else { None },
         }
     }
 
@@ -375,7 +700,7 @@ impl StatusArgs {
 }
 
 // Data structures for live service state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct LiveServiceState {
     pub name: String,
     pub project: String,
@@ -387,9 +712,15 @@ pub struct LiveServiceState {
     pub last_error: Option<String>,
     pub recent_logs: Vec<String>,
     pub resource_usage: Option<ResourceUsage>,
+    /// Times `--supervise` has restarted this service so far.
+    pub restart_count: u32,
+    pub last_restart: Option<std::time::SystemTime>,
+    /// Set once `restart_count` hit the service's `max_retries`, so the
+    /// supervisor has stopped trying.
+    pub restart_gave_up: bool,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum ServiceStatus {
     Running,
     Starting,
@@ -398,7 +729,7 @@ pub enum ServiceStatus {
     Restarting,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum ServiceHealth {
     Healthy,
     Unhealthy,
@@ -406,7 +737,7 @@ pub enum ServiceHealth {
     Unknown,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ResourceUsage {
     pub cpu_percent: f32,
     pub memory_mb: u64,
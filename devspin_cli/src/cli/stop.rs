@@ -3,7 +3,9 @@ use colored::*;
 use crate::error::{Result, ToolError};
 use crate::process::manager::ProcessManager;
 use crate::process::global::get_global_state;
+use crate::process::signal;
 use crate::ProcessInfo;
+use std::collections::{HashMap, VecDeque};
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
@@ -127,8 +129,8 @@ impl StopArgs {
         }
         
         // Stop services in reverse dependency order
-        let sorted_services = self.sort_services_for_shutdown(&services_to_stop);
-        
+        let sorted_services = self.sort_services_for_shutdown(&services_to_stop)?;
+
         self.stop_services_gracefully(&sorted_services).await?;
         
         println!("{} {}", "✓".green(), format!("Project '{}' stopped successfully", project_name).bold());
@@ -156,7 +158,10 @@ impl StopArgs {
                     self.remove_process(service.pid);
                 }
                 Err(e) => {
-                    if self.force {
+                    // Containers have no OS pid for force_stop_service to
+                    // signal; stop_container_service already asked Docker
+                    // for a force removal, so a failure there is terminal.
+                    if self.force && service.container_id.is_none() {
                         println!("  {} {}", "!".yellow(), "Graceful stop failed, forcing...".yellow());
                         self.force_stop_service(service).await?;
                         stopped_count += 1;
@@ -180,9 +185,13 @@ impl StopArgs {
     }
     
     async fn stop_single_service(&self, service: &ProcessInfo) -> Result<()> {
+        if let Some(container_id) = &service.container_id {
+            return self.stop_container_service(service, container_id).await;
+        }
+
         let start_time = Instant::now();
         let timeout = Duration::from_secs(self.timeout);
-        
+
         // Send SIGTERM first (graceful shutdown)
         if self.verbose {
             println!("  {} Sending graceful shutdown signal...", "WAIT".dimmed());
@@ -205,6 +214,23 @@ impl StopArgs {
         )))
     }
     
+    /// Container equivalent of `stop_single_service`: there's no OS pid of
+    /// ours to signal, so this tears the container down through the Docker
+    /// API instead (analogous to `docker compose down`). Docker's own stop
+    /// already escalates from a graceful stop to a kill after a timeout, so
+    /// there's no separate force path to mirror here.
+    async fn stop_container_service(&self, service: &ProcessInfo, container_id: &str) -> Result<()> {
+        if self.verbose {
+            println!("  {} Stopping container {}...", "WAIT".dimmed(), &container_id[..container_id.len().min(12)]);
+        }
+
+        crate::process::container::stop_container(container_id)
+            .await
+            .map_err(|e| ToolError::ProcessError(format!(
+                "Failed to stop container for service {}: {}", service.service_name, e
+            )))
+    }
+
     async fn force_stop_service(&self, service: &ProcessInfo) -> Result<()> {
         if self.verbose {
             println!("  {} Sending SIGKILL...", "FORCE".dimmed());
@@ -225,15 +251,81 @@ impl StopArgs {
         Ok(())
     }
     
-    fn sort_services_for_shutdown(&self, services: &[ProcessInfo]) -> Vec<ProcessInfo> {
-        // For shutdown, we want to stop services in reverse dependency order
-        // So if A depends on B, stop A first, then B
-        let mut sorted = services.to_vec();
-        
-        // Simple reversal - in a real implementation you'd want proper dependency analysis
-        sorted.reverse();
-        
-        sorted
+    /// Computes a safe shutdown order via Kahn's algorithm: a service is
+    /// always stopped before anything it depends on. Dependencies that
+    /// aren't part of this shutdown set (e.g. filtered out by --only/--skip,
+    /// or belonging to another project) are ignored rather than treated as
+    /// edges, since there's nothing to order them against here.
+    fn sort_services_for_shutdown(&self, services: &[ProcessInfo]) -> Result<Vec<ProcessInfo>> {
+        let names: std::collections::HashSet<&str> =
+            services.iter().map(|s| s.service_name.as_str()).collect();
+
+        // in_degree counts how many (in-set) dependencies each service has;
+        // dependents maps a service to the services that depend on it.
+        let mut in_degree: HashMap<&str, usize> = HashMap::new();
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+
+        for service in services {
+            in_degree.entry(service.service_name.as_str()).or_insert(0);
+
+            for dep in &service.dependencies {
+                if !names.contains(dep.as_str()) {
+                    continue;
+                }
+
+                *in_degree.entry(service.service_name.as_str()).or_insert(0) += 1;
+                dependents
+                    .entry(dep.as_str())
+                    .or_default()
+                    .push(service.service_name.as_str());
+            }
+        }
+
+        let mut queue: VecDeque<&str> = in_degree
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&name, _)| name)
+            .collect();
+
+        let mut start_order = Vec::with_capacity(services.len());
+
+        while let Some(name) = queue.pop_front() {
+            start_order.push(name);
+
+            if let Some(deps) = dependents.get(name) {
+                for &dependent in deps {
+                    let degree = in_degree.get_mut(dependent).unwrap();
+                    *degree -= 1;
+                    if *degree == 0 {
+                        queue.push_back(dependent);
+                    }
+                }
+            }
+        }
+
+        if start_order.len() != names.len() {
+            let remaining: Vec<&str> = names
+                .into_iter()
+                .filter(|name| !start_order.contains(name))
+                .collect();
+            return Err(ToolError::ConfigError(format!(
+                "dependency cycle detected among services: {}",
+                remaining.join(", ")
+            )));
+        }
+
+        // Shutdown order is the exact reverse of the start order, so a
+        // service is always torn down before the things it depends on.
+        let mut by_name: HashMap<&str, ProcessInfo> = services
+            .iter()
+            .map(|s| (s.service_name.as_str(), s.clone()))
+            .collect();
+
+        Ok(start_order
+            .into_iter()
+            .rev()
+            .map(|name| by_name.remove(name).expect("name came from this service set"))
+            .collect())
     }
     
     fn should_stop_service(&self, service: &ProcessInfo) -> bool {
@@ -298,11 +390,12 @@ impl StopArgs {
                 .into_iter()
                 .filter(|service| self.should_stop_service(service))
                 .collect();
-                
+            let sorted_services = self.sort_services_for_shutdown(&services_to_stop)?;
+
             println!("{} {}", "Would stop project:".dimmed(), project_name.bold());
-            println!("{} {} services", "Would stop:".dimmed(), services_to_stop.len());
-            
-            for service in services_to_stop {
+            println!("{} {} services (in shutdown order)", "Would stop:".dimmed(), sorted_services.len());
+
+            for service in sorted_services {
                 let stop_type = if self.force { "FORCE STOP".red() } else { "Graceful stop".green() };
                 println!("  • {} (PID: {}) - {}", service.service_name, service.pid, stop_type);
             }
@@ -324,42 +417,15 @@ impl StopArgs {
     }
     
     fn stop_process(&self, pid: u32) -> Result<()> {
-        // Send SIGTERM
-        let output = std::process::Command::new("kill")
-            .arg(pid.to_string())
-            .output()
-            .map_err(|e| ToolError::ProcessError(format!("Failed to send SIGTERM to PID {}: {}", pid, e)))?;
-            
-        if !output.status.success() {
-            return Err(ToolError::ProcessError(format!("kill command failed for PID {}", pid)));
-        }
-        
-        Ok(())
+        signal::send_sigterm(pid)
     }
-    
+
     fn kill_process(&self, pid: u32) -> Result<()> {
-        // Send SIGKILL
-        let output = std::process::Command::new("kill")
-            .arg("-9")
-            .arg(pid.to_string())
-            .output()
-            .map_err(|e| ToolError::ProcessError(format!("Failed to send SIGKILL to PID {}: {}", pid, e)))?;
-            
-        if !output.status.success() {
-            return Err(ToolError::ProcessError(format!("kill -9 command failed for PID {}", pid)));
-        }
-        
-        Ok(())
+        signal::send_sigkill(pid)
     }
-    
+
     fn is_process_running(&self, pid: u32) -> bool {
-        // Check if process exists by sending signal 0
-        std::process::Command::new("kill")
-            .arg("-0")
-            .arg(pid.to_string())
-            .output()
-            .map(|output| output.status.success())
-            .unwrap_or(false)
+        signal::is_alive(pid)
     }
     
     fn remove_process(&self, pid: u32) {
@@ -0,0 +1,45 @@
+//! Small edit-distance utility shared by anything that needs a "did you
+//! mean" suggestion for a mistyped service name — `devspin fix` rewriting a
+//! misspelled dependency, and filter-name validation doing the same for
+//! `--only`/`--skip`.
+
+/// Classic Levenshtein DP table: `d[i][j]` is the edit distance between the
+/// first `i` characters of `a` and the first `j` characters of `b`.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (rows, cols) = (a.len() + 1, b.len() + 1);
+
+    let mut d = vec![vec![0usize; cols]; rows];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..cols {
+        d[0][j] = j;
+    }
+
+    for i in 1..rows {
+        for j in 1..cols {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+        }
+    }
+
+    d[rows - 1][cols - 1]
+}
+
+/// Closest entry in `candidates` to `name`, if any is within a reasonable
+/// distance (`max(2, len/3)`) to avoid nonsense suggestions on a name that
+/// just isn't close to anything.
+pub fn closest_match<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let threshold = (name.chars().count() / 3).max(2);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, levenshtein(name, candidate)))
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, ToolError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectConfig {
+    pub name: String,
+    pub description: Option<String>,
+    pub commands: Commands,
+    pub services: Option<Vec<Service>>,
+    pub environment: Option<HashMap<String, String>>,
+    pub hooks: Option<Hooks>,
+
+    /// Directory the devspin.yaml was loaded from, used to resolve relative
+    /// service working directories. Not part of the YAML itself.
+    #[serde(skip)]
+    pub base_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Commands {
+    pub start: StartCommands,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartCommands {
+    pub dev: String,
+    pub build: String,
+    pub test: Option<String>,
+    pub clean: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Service {
+    pub name: String,
+    pub command: String,
+    #[serde(rename = "type", default = "default_service_type")]
+    pub service_type: String,
+    pub working_dir: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    pub health_check: Option<HealthCheck>,
+    #[serde(default)]
+    pub restart: RestartPolicy,
+    /// Base delay (seconds) before the first restart attempt; the
+    /// supervisor doubles this on each consecutive failure, capped at
+    /// `max_delay_secs`.
+    #[serde(default = "default_restart_delay")]
+    pub restart_delay: u64,
+    /// Upper bound (seconds) on the exponential restart backoff.
+    #[serde(default = "default_max_delay_secs")]
+    pub max_delay_secs: u64,
+    /// Consecutive failures allowed before the supervisor gives up on this
+    /// service entirely.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// How long a service must stay up before a later crash is treated as a
+    /// fresh failure rather than another consecutive one.
+    #[serde(default = "default_success_threshold_secs")]
+    pub success_threshold_secs: u64,
+    /// When true, the service isn't started eagerly; DevSpin binds `listen`
+    /// itself and only spawns the real process on the first connection.
+    /// Accepts `on_demand` as an alias, the more descriptive name some
+    /// configs use for the same socket-activation behavior.
+    #[serde(alias = "on_demand", default)]
+    pub lazy: bool,
+    /// Address DevSpin binds to on the service's behalf when `lazy` is set.
+    pub listen: Option<String>,
+    /// Seconds of inactivity after which a lazy service is stopped again.
+    pub idle_timeout: Option<u64>,
+    /// Minimum number of healthy instances of this service that should be
+    /// running at any time; the instance enforcer (re)spawns more if the
+    /// count ever drops below this.
+    #[serde(default = "default_min_instances")]
+    pub min_instances: u32,
+    /// Paths to watch for changes in `devspin start --watch`; defaults to
+    /// this service's resolved `working_dir` if omitted.
+    pub watch_paths: Option<Vec<String>>,
+    /// Substrings of paths to ignore while watching (e.g. "target/", ".git/").
+    pub ignore: Option<Vec<String>>,
+    /// Seconds a dependent service will wait for each of its dependencies
+    /// to become ready before `devspin start` gives up.
+    #[serde(default = "default_depends_on_timeout")]
+    pub depends_on_timeout: u64,
+
+    // --- `type: docker` / `type: compose` services ---
+    // These are only read when `service_type` is "docker" or "compose";
+    // `command` still applies, but as an optional CMD override run through
+    // `sh -c` inside the container rather than on the host.
+    /// Image to pull (if not already present) and run.
+    pub image: Option<String>,
+    /// `host:container` port mappings, e.g. `"8080:80"`.
+    pub ports: Option<Vec<String>>,
+    /// `host:container` bind mounts, e.g. `"./data:/var/lib/postgresql/data"`.
+    pub volumes: Option<Vec<String>>,
+    /// Environment variables to set inside the container.
+    pub env: Option<HashMap<String, String>>,
+
+    /// Threshold-based health conditions `devspin status` evaluates in
+    /// addition to `health_check`, e.g. flagging a service unhealthy once
+    /// its CPU has stayed above a limit for long enough. Empty by default,
+    /// since most services are fine being judged on liveness/health_check
+    /// alone.
+    #[serde(default)]
+    pub state_matchers: Vec<StateMatcherConfig>,
+
+    /// Seconds of no log output/connection activity after which `devspin
+    /// status --watch-idle` stops this service. Distinct from `idle_timeout`
+    /// above, which only ever applies to `lazy` (start-on-demand) services;
+    /// this applies to any service and is the stop-when-idle half of
+    /// on-demand lifecycle management.
+    pub watch_idle_timeout_secs: Option<u64>,
+}
+
+/// A single threshold-style health condition a service can declare, evaluated
+/// by `process::state_matcher` once its `duration_secs` has elapsed
+/// continuously so a momentary spike doesn't flap the service's status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum StateMatcherConfig {
+    CpuAbove { percent: f32, duration_secs: u64 },
+    MemoryAbove { mb: u64, duration_secs: u64 },
+    Liveness { duration_secs: u64 },
+}
+
+/// True for `service_type`s devspin manages as containers rather than
+/// shelling out to `sh -c`.
+pub fn is_container_service_type(service_type: &str) -> bool {
+    matches!(service_type, "docker" | "compose")
+}
+
+fn default_depends_on_timeout() -> u64 {
+    30
+}
+
+fn default_service_type() -> String {
+    "process".to_string()
+}
+
+fn default_restart_delay() -> u64 {
+    2
+}
+
+fn default_max_delay_secs() -> u64 {
+    60
+}
+
+fn default_max_retries() -> u32 {
+    5
+}
+
+fn default_success_threshold_secs() -> u64 {
+    10
+}
+
+fn default_min_instances() -> u32 {
+    1
+}
+
+fn default_health_interval() -> u64 {
+    10
+}
+
+/// When (if ever) a supervised service should be automatically restarted
+/// after it exits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    #[default]
+    Never,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthCheck {
+    #[serde(rename = "type")]
+    pub type_entry: String,
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub http_target: String,
+    /// Shell command to run for a `type: shell` check; its exit status
+    /// determines health.
+    pub command: Option<String>,
+    /// How often (in seconds) the background monitor re-checks this service
+    /// once it's already running. Not to be confused with `interval_ms`
+    /// below, which governs the one-shot startup gate.
+    #[serde(default = "default_health_interval")]
+    pub interval_secs: u64,
+    /// Base delay (in ms) before the startup gate's first retry; each
+    /// subsequent attempt doubles this, capped at `MAX_PROBE_BACKOFF`.
+    #[serde(default = "default_startup_interval_ms")]
+    pub interval_ms: u64,
+    /// Overall deadline (in seconds) for the startup health-check gate
+    /// before `devspin start` gives up on this service.
+    pub timeout_secs: Option<u64>,
+    /// Max number of polls during the startup gate before giving up.
+    pub retries: Option<u32>,
+    /// How long a single probe attempt (the connect/request itself) may take
+    /// before it's treated as a failure and retried.
+    #[serde(default = "default_probe_timeout_ms")]
+    pub probe_timeout_ms: u64,
+    /// Path appended to `http://127.0.0.1:<port>` for an HTTP check, as a
+    /// lighter-weight alternative to spelling out the whole `http_target`.
+    pub path: Option<String>,
+    /// Status code an HTTP check must see to pass; defaults to any 2xx/3xx
+    /// when unset.
+    pub expected_status: Option<u16>,
+}
+
+fn default_startup_interval_ms() -> u64 {
+    500
+}
+
+fn default_probe_timeout_ms() -> u64 {
+    2000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hooks {
+    pub pre_start: Option<String>,
+    pub post_start: Option<String>,
+    pub pre_stop: Option<String>,
+    pub post_stop: Option<String>,
+}
+
+impl ProjectConfig {
+    pub fn from_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            ToolError::ConfigError(format!("Failed to read config file {}: {}", path, e))
+        })?;
+
+        let mut config: ProjectConfig = serde_yaml::from_str(&content)?;
+        config.base_path = Path::new(path).parent().map(|p| p.to_path_buf());
+        config.validate_dependencies()?;
+
+        Ok(config)
+    }
+
+    /// Resolves a service-relative path against the directory the project's
+    /// devspin.yaml was loaded from.
+    pub fn resolve_path(&self, relative: &str) -> PathBuf {
+        match &self.base_path {
+            Some(base) => base.join(relative),
+            None => PathBuf::from(relative),
+        }
+    }
+
+    /// Ensures every `dependencies` entry names a service that actually
+    /// exists in this project, so a typo fails fast instead of silently
+    /// being ignored by the dependency resolver.
+    pub fn validate_dependencies(&self) -> Result<()> {
+        let Some(services) = &self.services else {
+            return Ok(());
+        };
+
+        let names: std::collections::HashSet<&str> =
+            services.iter().map(|s| s.name.as_str()).collect();
+
+        for service in services {
+            for dep in &service.dependencies {
+                if !names.contains(dep.as_str()) {
+                    return Err(ToolError::ConfigError(format!(
+                        "service '{}' depends on unknown service '{}'",
+                        service.name, dep
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ToolError {
+    ConfigError(String),
+    ProcessError(String),
+    IoError(String),
+}
+
+pub type Result<T> = std::result::Result<T, ToolError>;
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToolError::ConfigError(msg) => write!(f, "Config error: {}", msg),
+            ToolError::ProcessError(msg) => write!(f, "Process error: {}", msg),
+            ToolError::IoError(msg) => write!(f, "IO error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ToolError {}
+
+impl From<std::io::Error> for ToolError {
+    fn from(err: std::io::Error) -> Self {
+        ToolError::IoError(err.to_string())
+    }
+}
+
+impl From<serde_yaml::Error> for ToolError {
+    fn from(err: serde_yaml::Error) -> Self {
+        ToolError::ConfigError(format!("Failed to parse YAML: {}", err))
+    }
+}
@@ -0,0 +1,6 @@
+pub mod cli;
+pub mod configs;
+pub mod error;
+pub mod process;
+
+pub use process::state::{ProcessInfo, ProcessStatus};
@@ -0,0 +1,20 @@
+use clap::Parser;
+use devspin_cli::cli::{welcome_message::welcome_message, Cli, Commands};
+use devspin_cli::error::Result;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::init();
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Commands::Start(args)) => args.execute().await?,
+        Some(Commands::Stop(args)) => args.execute().await?,
+        Some(Commands::Status(args)) => args.execute().await?,
+        Some(Commands::Logs(args)) => args.execute().await?,
+        Some(Commands::Fix(args)) => args.execute().await?,
+        None => welcome_message(),
+    }
+
+    Ok(())
+}
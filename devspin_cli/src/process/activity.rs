@@ -0,0 +1,35 @@
+//! Per-service activity tracking for `devspin status --watch-idle`'s
+//! idle-shutdown reaper. This is a separate, opt-in policy from
+//! `process::reaper`'s lazy-activation idle timeout: that one only ever
+//! applies to `lazy` (start-on-demand) services and is driven by proxied
+//! connections, while `--watch-idle` can stop *any* tracked service once its
+//! `watch_idle_timeout_secs` has elapsed with no log output or observed
+//! connection.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+
+static LAST_ACTIVE: Lazy<Mutex<HashMap<u32, Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Records that `pid` was just seen doing something (new log output, or an
+/// observed connection), resetting its idle clock.
+pub fn touch(pid: u32) {
+    LAST_ACTIVE.lock().unwrap().insert(pid, Instant::now());
+}
+
+/// How long `pid` has gone without being `touch`ed. A pid seen for the
+/// first time is treated as active right now, so a service isn't stopped
+/// the moment `--watch-idle` starts watching it.
+pub fn idle_for(pid: u32) -> Duration {
+    let mut last_active = LAST_ACTIVE.lock().unwrap();
+    let now = Instant::now();
+    last_active.entry(pid).or_insert(now).elapsed()
+}
+
+/// Drops a pid's activity state once it's no longer tracked.
+pub fn forget(pid: u32) {
+    LAST_ACTIVE.lock().unwrap().remove(&pid);
+}
@@ -0,0 +1,177 @@
+//! Container lifecycle for `type: docker` / `type: compose` services, via
+//! the Docker Engine API (through the `bollard` client) rather than a local
+//! `sh -c` process. Keeps shell and container services interchangeable from
+//! everywhere else's point of view: both end up as a tracked `ProcessInfo`,
+//! so health checks, dependency ordering and `devspin status` don't need to
+//! know which kind they're looking at.
+
+use std::collections::HashMap;
+
+use bollard::container::{
+    Config, CreateContainerOptions, RemoveContainerOptions, StartContainerOptions,
+    StopContainerOptions,
+};
+use bollard::image::CreateImageOptions;
+use bollard::models::{HostConfig, PortBinding};
+use bollard::Docker;
+use futures_util::stream::StreamExt;
+
+use crate::error::{Result, ToolError};
+
+/// Everything needed to create and start a container for one service
+/// instance. Borrowed rather than owning a `Service`/`ProcessInfo` so it can
+/// be built from either: a fresh `devspin start` has the full `Service`, a
+/// supervisor respawn only has the `ProcessInfo` snapshot taken at the
+/// original spawn.
+pub struct ContainerSpec<'a> {
+    pub container_name: &'a str,
+    pub image: &'a str,
+    pub command: Option<&'a str>,
+    pub ports: &'a [String],
+    pub volumes: &'a [String],
+    pub env: &'a HashMap<String, String>,
+}
+
+fn connect() -> Result<Docker> {
+    Docker::connect_with_local_defaults()
+        .map_err(|e| ToolError::ProcessError(format!("failed to connect to Docker: {}", e)))
+}
+
+/// Pulls `spec.image` if it isn't already present, creates a container from
+/// `spec`, starts it, and returns its container id.
+pub async fn start_container(spec: ContainerSpec<'_>) -> Result<String> {
+    let docker = connect()?;
+
+    pull_image_if_missing(&docker, spec.image).await?;
+
+    let port_bindings = build_port_bindings(spec.ports);
+    let exposed_ports = port_bindings
+        .keys()
+        .map(|port| (port.clone(), HashMap::new()))
+        .collect();
+
+    let config = Config {
+        image: Some(spec.image.to_string()),
+        cmd: spec.command.map(|c| vec!["sh".to_string(), "-c".to_string(), c.to_string()]),
+        env: Some(
+            spec.env
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect(),
+        ),
+        exposed_ports: Some(exposed_ports),
+        host_config: Some(HostConfig {
+            port_bindings: Some(port_bindings),
+            binds: Some(spec.volumes.to_vec()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let options = CreateContainerOptions {
+        name: spec.container_name.to_string(),
+        platform: None,
+    };
+
+    let created = docker
+        .create_container(Some(options), config)
+        .await
+        .map_err(|e| {
+            ToolError::ProcessError(format!(
+                "failed to create container for '{}': {}",
+                spec.container_name, e
+            ))
+        })?;
+
+    docker
+        .start_container(&created.id, None::<StartContainerOptions<String>>)
+        .await
+        .map_err(|e| {
+            ToolError::ProcessError(format!(
+                "failed to start container '{}': {}",
+                spec.container_name, e
+            ))
+        })?;
+
+    Ok(created.id)
+}
+
+/// Tears a container down the way `docker compose down` would: stop, then
+/// remove so a later `devspin start` can recreate it under the same name.
+pub async fn stop_container(container_id: &str) -> Result<()> {
+    let docker = connect()?;
+
+    // Already gone is not an error here - stopping is idempotent.
+    let _ = docker
+        .stop_container(container_id, Some(StopContainerOptions { t: 10 }))
+        .await;
+
+    docker
+        .remove_container(
+            container_id,
+            Some(RemoveContainerOptions {
+                force: true,
+                ..Default::default()
+            }),
+        )
+        .await
+        .map_err(|e| {
+            ToolError::ProcessError(format!("failed to remove container '{}': {}", container_id, e))
+        })?;
+
+    Ok(())
+}
+
+/// Whether the container is still present and running: the container-backed
+/// equivalent of a PID presence check.
+pub async fn is_container_running(container_id: &str) -> bool {
+    let Ok(docker) = connect() else {
+        return false;
+    };
+
+    docker
+        .inspect_container(container_id, None)
+        .await
+        .ok()
+        .and_then(|details| details.state)
+        .and_then(|state| state.running)
+        .unwrap_or(false)
+}
+
+async fn pull_image_if_missing(docker: &Docker, image: &str) -> Result<()> {
+    if docker.inspect_image(image).await.is_ok() {
+        return Ok(());
+    }
+
+    let options = CreateImageOptions {
+        from_image: image.to_string(),
+        ..Default::default()
+    };
+
+    let mut stream = docker.create_image(Some(options), None, None);
+    while let Some(progress) = stream.next().await {
+        progress.map_err(|e| ToolError::ProcessError(format!("failed to pull image '{}': {}", image, e)))?;
+    }
+
+    Ok(())
+}
+
+fn build_port_bindings(ports: &[String]) -> HashMap<String, Option<Vec<PortBinding>>> {
+    let mut bindings = HashMap::new();
+
+    for mapping in ports {
+        let Some((host_port, container_port)) = mapping.split_once(':') else {
+            continue;
+        };
+
+        bindings.insert(
+            format!("{}/tcp", container_port),
+            Some(vec![PortBinding {
+                host_ip: None,
+                host_port: Some(host_port.to_string()),
+            }]),
+        );
+    }
+
+    bindings
+}
@@ -0,0 +1,166 @@
+//! Minimum-instance enforcement: keeps at least `min_instances` healthy
+//! copies of each service running, (re)spawning any that are missing.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use log::{error, info, warn};
+use tokio::time::sleep;
+
+use crate::process::global::get_global_state;
+use crate::process::state::{ProcessInfo, ProcessStatus};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Starts the background task that watches each service's healthy instance
+/// count and tops it back up to `min_instances` when it falls short.
+pub fn spawn_instance_enforcer() {
+    tokio::spawn(async move {
+        loop {
+            sleep(POLL_INTERVAL).await;
+            enforce_tick().await;
+        }
+    });
+}
+
+async fn enforce_tick() {
+    let (missing, errored): (Vec<ProcessInfo>, Vec<ProcessInfo>) = {
+        let state = get_global_state();
+
+        // (project, service) -> (healthy count, a template instance to clone)
+        let mut groups: HashMap<(String, String), (u32, ProcessInfo)> = HashMap::new();
+        let mut errored = Vec::new();
+
+        for running in state.get_all_processes().values() {
+            let key = (running.info.project_name.clone(), running.info.service_name.clone());
+            let entry = groups.entry(key).or_insert_with(|| (0, running.info.clone()));
+            match running.info.status {
+                ProcessStatus::Running => entry.0 += 1,
+                ProcessStatus::Error(_) => errored.push(running.info.clone()),
+                ProcessStatus::Stopped => {}
+            }
+        }
+
+        let missing = groups
+            .into_values()
+            .flat_map(|(healthy, template)| {
+                let missing_count = template.min_instances.saturating_sub(healthy);
+                std::iter::repeat(template).take(missing_count as usize)
+            })
+            .collect();
+
+        (missing, errored)
+    };
+
+    // Reap instances the health monitor flipped to `Error` in place before
+    // topping back up: left tracked, they'd never count as healthy but
+    // also never go away, so every tick would spawn another replacement
+    // on top of an ever-growing pile of live, unhealthy zombies.
+    for info in errored {
+        reap_instance(info).await;
+    }
+
+    for template in missing {
+        spawn_instance(template).await;
+    }
+}
+
+async fn reap_instance(info: ProcessInfo) {
+    let reaped = {
+        let mut state = get_global_state();
+        state.reap_errored(info.pid)
+    };
+
+    let Some(reaped) = reaped else { return };
+
+    if let Some(container_id) = &reaped.container_id {
+        if let Err(e) = crate::process::container::stop_container(container_id).await {
+            warn!(
+                "failed to stop errored container for {} ({}): {}",
+                reaped.service_name, reaped.project_name, e
+            );
+        }
+    }
+
+    info!(
+        "reaped unhealthy instance of {} ({})",
+        reaped.service_name, reaped.project_name
+    );
+}
+
+async fn spawn_instance(mut info: ProcessInfo) {
+    if let Some(image) = info.image.clone() {
+        spawn_container_instance(info, &image).await;
+        return;
+    }
+
+    let mut command = std::process::Command::new("sh");
+    command.arg("-c").arg(&info.command);
+
+    if let Some(working_dir) = &info.working_dir {
+        command.current_dir(working_dir);
+    }
+
+    match crate::process::logging::open_service_log(&info.project_name, &info.service_name) {
+        Ok((log_path, stdout, stderr)) => {
+            command.stdout(stdout);
+            command.stderr(stderr);
+            info.log_path = Some(log_path);
+        }
+        Err(e) => warn!(
+            "failed to open log file for service {} ({}): {}",
+            info.service_name, info.project_name, e
+        ),
+    }
+
+    match command.spawn() {
+        Ok(child) => {
+            info!(
+                "enforcing min_instances: starting another instance of {} ({})",
+                info.service_name, info.project_name
+            );
+            let mut state = get_global_state();
+            state.reinsert(info, child);
+        }
+        Err(e) => {
+            error!(
+                "failed to start required instance of {} ({}): {}",
+                info.service_name, info.project_name, e
+            );
+        }
+    }
+}
+
+async fn spawn_container_instance(info: ProcessInfo, image: &str) {
+    let env = info.container_env.clone().unwrap_or_default();
+    let ports = info.ports.clone().unwrap_or_default();
+    let volumes = info.volumes.clone().unwrap_or_default();
+    let command = (!info.command.is_empty()).then_some(info.command.as_str());
+    let container_name = format!("devspin-{}-{}", info.project_name, info.service_name);
+
+    let spec = crate::process::container::ContainerSpec {
+        container_name: &container_name,
+        image,
+        command,
+        ports: &ports,
+        volumes: &volumes,
+        env: &env,
+    };
+
+    match crate::process::container::start_container(spec).await {
+        Ok(container_id) => {
+            info!(
+                "enforcing min_instances: starting another container for {} ({})",
+                info.service_name, info.project_name
+            );
+            let mut state = get_global_state();
+            state.reinsert_container(info, container_id);
+        }
+        Err(e) => {
+            error!(
+                "failed to start required container instance of {} ({}): {}",
+                info.service_name, info.project_name, e
+            );
+        }
+    }
+}
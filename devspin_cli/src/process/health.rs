@@ -0,0 +1,104 @@
+//! Background health-check monitor. A PID existing doesn't mean a service
+//! is actually serving traffic, so this periodically probes every tracked
+//! process that declares a `health_check` and keeps its `ProcessStatus` in
+//! sync with whether it's really responding.
+
+use std::net::TcpStream;
+use std::time::Duration;
+
+use log::warn;
+use tokio::time::sleep;
+
+use crate::configs::yaml_parser::HealthCheck;
+use crate::process::global::get_global_state;
+use crate::process::state::ProcessStatus;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Starts the background task that re-checks each tracked service once its
+/// own `health_check.interval_secs` has elapsed since the last probe.
+pub fn spawn_health_monitor() {
+    tokio::spawn(async move {
+        loop {
+            sleep(POLL_INTERVAL).await;
+            health_tick().await;
+        }
+    });
+}
+
+async fn health_tick() {
+    let due: Vec<(u32, HealthCheck)> = {
+        let state = get_global_state();
+        state
+            .get_all_processes()
+            .values()
+            .filter_map(|p| {
+                let health_check = p.info.health_check.clone()?;
+                let elapsed = p.info.last_health_check.elapsed().ok()?;
+                (elapsed >= Duration::from_secs(health_check.interval_secs.max(1)))
+                    .then_some((p.info.pid, health_check))
+            })
+            .collect()
+    };
+
+    for (pid, health_check) in due {
+        let healthy = run_check(&health_check).await;
+
+        let mut state = get_global_state();
+        state.touch_health_check(pid);
+        state.set_status(
+            pid,
+            if healthy {
+                ProcessStatus::Running
+            } else {
+                ProcessStatus::Error("health check failed".to_string())
+            },
+        );
+
+        if !healthy {
+            warn!("health check failed for PID {}", pid);
+        }
+    }
+}
+
+async fn run_check(health_check: &HealthCheck) -> bool {
+    match health_check.type_entry.as_str() {
+        "shell" => health_check
+            .command
+            .as_ref()
+            .map(|command| {
+                std::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(command)
+                    .status()
+                    .map(|status| status.success())
+                    .unwrap_or(false)
+            })
+            .unwrap_or(true),
+        "http" => {
+            let target = match &health_check.path {
+                Some(path) => format!("http://127.0.0.1:{}{}", health_check.port.unwrap_or(80), path),
+                None => health_check.http_target.clone(),
+            };
+
+            match reqwest::get(&target).await {
+                Ok(response) => http_status_matches(health_check, response.status()),
+                Err(_) => false,
+            }
+        }
+        "port" | "tcp" => health_check
+            .port
+            .map(|port| TcpStream::connect(("127.0.0.1", port)).is_ok())
+            .unwrap_or(true),
+        _ => true,
+    }
+}
+
+/// Same pass/fail rule `devspin start`'s startup gate uses: an explicit
+/// `expected_status` must match exactly, otherwise any 2xx/3xx counts.
+fn http_status_matches(health_check: &HealthCheck, status: reqwest::StatusCode) -> bool {
+    match health_check.expected_status {
+        Some(expected) => status.as_u16() == expected,
+        None => status.is_success() || status.is_redirection(),
+    }
+}
@@ -0,0 +1,184 @@
+//! Skips re-running a service's startup health check when nothing about it
+//! has changed since the last successful `devspin start`: the resolved
+//! health-check/service config is unchanged and none of the files it depends
+//! on have been touched. Entries are cached per (project, service), keyed on
+//! a hash of the config plus a cheap mtime fingerprint, with a content-hash
+//! fallback for the coarse-mtime race where a fast edit lands in the same
+//! one-second bucket as the cache write.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+
+use crate::configs::yaml_parser::{HealthCheck, Service};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    config_hash: u64,
+    mtime_secs: u64,
+    content_hash: u64,
+}
+
+fn cache_path(project_name: &str, service_name: &str) -> PathBuf {
+    PathBuf::from(".devspin_cache")
+        .join(project_name)
+        .join(format!("{}.yaml", service_name))
+}
+
+/// Hashes everything about a service that would make a past "healthy"
+/// verdict no longer trustworthy: how it's started and how it's checked.
+/// Serializing `health_check` rather than hand-listing its fields means a
+/// new field here is covered automatically instead of silently ignored.
+fn config_hash(service: &Service, health_check: &HealthCheck) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    service.command.hash(&mut hasher);
+    service.service_type.hash(&mut hasher);
+    service.image.hash(&mut hasher);
+    if let Ok(yaml) = serde_yaml::to_string(health_check) {
+        yaml.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Paths this service's health depends on. `wait_for_health_check` has no
+/// `ProjectConfig` to resolve against (it's also called from the lazy
+/// on-demand listener), so these are taken as-is, relative to the current
+/// directory, rather than resolved against the project base path the way
+/// `devspin start` resolves `working_dir` when spawning the service.
+fn dependency_roots(service: &Service) -> Vec<PathBuf> {
+    if let Some(paths) = &service.watch_paths {
+        paths.iter().map(PathBuf::from).collect()
+    } else if let Some(dir) = &service.working_dir {
+        vec![PathBuf::from(dir)]
+    } else {
+        vec![PathBuf::from(".")]
+    }
+}
+
+fn walk_files(dir: &Path, ignore: &[String], visit: &mut impl FnMut(&Path)) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let path_str = path.to_string_lossy();
+
+        if ignore.iter().any(|pattern| path_str.contains(pattern.as_str())) {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            walk_files(&path, ignore, visit);
+        } else {
+            visit(&path);
+        }
+    }
+}
+
+fn latest_mtime_secs(roots: &[PathBuf], ignore: &[String]) -> u64 {
+    let mut latest = UNIX_EPOCH;
+
+    for root in roots {
+        walk_files(root, ignore, &mut |path| {
+            if let Ok(modified) = fs::metadata(path).and_then(|m| m.modified()) {
+                if modified > latest {
+                    latest = modified;
+                }
+            }
+        });
+    }
+
+    latest.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// Fallback for the coarse-mtime race: hashes every dependency file's path
+/// and contents. A lookup compares it only when the mtime check alone can't
+/// tell whether something changed; `record_healthy` computes it every time.
+fn content_hash(roots: &[PathBuf], ignore: &[String]) -> u64 {
+    let mut paths = Vec::new();
+    for root in roots {
+        walk_files(root, ignore, &mut |path| paths.push(path.to_path_buf()));
+    }
+    paths.sort();
+
+    let mut hasher = DefaultHasher::new();
+    for path in paths {
+        path.hash(&mut hasher);
+        if let Ok(bytes) = fs::read(&path) {
+            bytes.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+/// Whether a previously-recorded "healthy" verdict for this service still
+/// holds: same resolved config, and nothing under its dependency paths has
+/// changed since.
+pub fn is_cached_healthy(project_name: &str, service: &Service, health_check: &HealthCheck) -> bool {
+    let Ok(raw) = fs::read_to_string(cache_path(project_name, &service.name)) else {
+        return false;
+    };
+    let Ok(entry) = serde_yaml::from_str::<CacheEntry>(&raw) else {
+        return false;
+    };
+
+    if entry.config_hash != config_hash(service, health_check) {
+        return false;
+    }
+
+    let roots = dependency_roots(service);
+    let ignore = service.ignore.clone().unwrap_or_default();
+    let current_mtime = latest_mtime_secs(&roots, &ignore);
+
+    match current_mtime.cmp(&entry.mtime_secs) {
+        Ordering::Greater => false,
+        Ordering::Less => true,
+        Ordering::Equal => content_hash(&roots, &ignore) == entry.content_hash,
+    }
+}
+
+/// Drops any cached "healthy" verdict for `service`, so the next
+/// `wait_for_health_check` for it always probes the current process rather
+/// than trusting a verdict recorded for a previous, now-dead one. Config and
+/// file state not having changed says nothing about whether a *freshly
+/// spawned* process is actually listening yet - only a real probe can tell
+/// you that - so `devspin start` calls this right after each spawn it follows
+/// with a startup health check, before that check. Respawns by the
+/// supervisor, the instance enforcer or the file watcher do not call it.
+pub fn invalidate(project_name: &str, service_name: &str) {
+    let _ = fs::remove_file(cache_path(project_name, service_name));
+}
+
+/// Records that `service` just passed its startup health check, so a
+/// following `devspin start` can skip re-probing it if nothing changed.
+pub fn record_healthy(project_name: &str, service: &Service, health_check: &HealthCheck) {
+    let roots = dependency_roots(service);
+    let ignore = service.ignore.clone().unwrap_or_default();
+
+    let entry = CacheEntry {
+        config_hash: config_hash(service, health_check),
+        mtime_secs: latest_mtime_secs(&roots, &ignore),
+        content_hash: content_hash(&roots, &ignore),
+    };
+
+    let path = cache_path(project_name, &service.name);
+    if let Some(parent) = path.parent() {
+        if fs::create_dir_all(parent).is_err() {
+            return;
+        }
+    }
+
+    if let Ok(yaml) = serde_yaml::to_string(&entry) {
+        let _ = fs::write(path, yaml);
+    }
+}
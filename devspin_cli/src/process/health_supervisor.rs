@@ -0,0 +1,192 @@
+//! `devspin status --supervise`'s auto-restart loop. Unlike
+//! `process::supervisor` (which only reacts to a tracked process actually
+//! exiting), this reacts to `devspin status`'s own health verdict: a process
+//! that's still alive but failing its health check or state matchers gets
+//! killed and respawned too. Restart bookkeeping is kept per (project,
+//! service) rather than by pid, since a respawned service comes back under a
+//! brand new pid.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+
+use crate::cli::status::ServiceHealth;
+use crate::process::global::get_global_state;
+use crate::process::state::ProcessInfo;
+
+#[derive(Debug, Clone, Default)]
+pub struct RestartRecord {
+    pub restart_count: u32,
+    pub last_restart: Option<SystemTime>,
+    /// Set once `restart_count` has reached the service's `max_retries`, so
+    /// a permanently-unhealthy service isn't retried forever.
+    pub gave_up: bool,
+}
+
+static RESTARTS: Lazy<Mutex<HashMap<(String, String), RestartRecord>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn key(process_info: &ProcessInfo) -> (String, String) {
+    (process_info.project_name.clone(), process_info.service_name.clone())
+}
+
+/// Current restart bookkeeping for a service, for display purposes only —
+/// does not itself attempt a restart.
+pub fn record_for(process_info: &ProcessInfo) -> RestartRecord {
+    RESTARTS
+        .lock()
+        .unwrap()
+        .get(&key(process_info))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// If `health` is `Unhealthy` and this service is due for another attempt
+/// (hasn't given up, and its exponential backoff since the last attempt has
+/// elapsed), kills the current process and respawns it from its original
+/// spawn command. Returns the service's restart bookkeeping plus whether a
+/// restart was actually attempted on this call, so the caller can show
+/// `ServiceStatus::Restarting` for it.
+pub async fn maybe_restart(process_info: &ProcessInfo, health: &ServiceHealth) -> (RestartRecord, bool) {
+    if !matches!(health, ServiceHealth::Unhealthy) {
+        return (record_for(process_info), false);
+    }
+
+    let service_key = key(process_info);
+    let should_attempt = {
+        let mut restarts = RESTARTS.lock().unwrap();
+        let record = restarts.entry(service_key.clone()).or_default();
+
+        if record.gave_up {
+            return (record.clone(), false);
+        }
+
+        if record.restart_count >= process_info.max_retries {
+            record.gave_up = true;
+            warn!(
+                "service {} ({}) gave up after {} restarts",
+                process_info.service_name, process_info.project_name, record.restart_count
+            );
+            return (record.clone(), false);
+        }
+
+        let backoff = Duration::from_secs(
+            process_info
+                .restart_delay_secs
+                .saturating_mul(2u64.saturating_pow(record.restart_count.min(16)))
+                .min(process_info.max_delay_secs),
+        );
+
+        match record.last_restart {
+            Some(last) => last.elapsed().unwrap_or_default() >= backoff,
+            None => true,
+        }
+    };
+
+    if !should_attempt {
+        return (record_for(process_info), false);
+    }
+
+    respawn(process_info).await;
+
+    let mut restarts = RESTARTS.lock().unwrap();
+    let record = restarts.entry(service_key).or_default();
+    record.restart_count += 1;
+    record.last_restart = Some(SystemTime::now());
+    info!(
+        "restarted service {} ({}) (attempt {}/{})",
+        process_info.service_name, process_info.project_name, record.restart_count, process_info.max_retries
+    );
+    (record.clone(), true)
+}
+
+async fn respawn(process_info: &ProcessInfo) {
+    let taken = {
+        let mut state = get_global_state();
+        state.take_by_service_name(&process_info.project_name, &process_info.service_name)
+    };
+    let Some(info) = taken else {
+        return;
+    };
+
+    // `take_by_service_name` only untracks a container (stopping one needs
+    // an `.await` it can't do), so a container service has to be stopped
+    // here before a new one is started in its place - otherwise the old
+    // container leaks and, since `info.command` is empty for an image-only
+    // service, shelling out below would just exit immediately and get
+    // reinserted as a dead "running" process.
+    if let Some(image) = info.image.clone() {
+        if let Some(container_id) = &info.container_id {
+            if let Err(e) = crate::process::container::stop_container(container_id).await {
+                warn!(
+                    "failed to stop container while restarting service {} ({}): {}",
+                    info.service_name, info.project_name, e
+                );
+            }
+        }
+        respawn_container(info, &image).await;
+    } else {
+        respawn_local(info).await;
+    }
+}
+
+async fn respawn_local(mut info: ProcessInfo) {
+    let mut command = std::process::Command::new("sh");
+    command.arg("-c").arg(&info.command);
+    if let Some(working_dir) = &info.working_dir {
+        command.current_dir(working_dir);
+    }
+
+    match crate::process::logging::open_service_log(&info.project_name, &info.service_name) {
+        Ok((log_path, stdout, stderr)) => {
+            command.stdout(stdout);
+            command.stderr(stderr);
+            info.log_path = Some(log_path);
+        }
+        Err(e) => warn!(
+            "failed to open log file while restarting service {} ({}): {}",
+            info.service_name, info.project_name, e
+        ),
+    }
+
+    match command.spawn() {
+        Ok(child) => {
+            let mut state = get_global_state();
+            state.reinsert(info, child);
+        }
+        Err(e) => error!(
+            "failed to restart service {} ({}): {}",
+            info.service_name, info.project_name, e
+        ),
+    }
+}
+
+async fn respawn_container(info: ProcessInfo, image: &str) {
+    let env = info.container_env.clone().unwrap_or_default();
+    let ports = info.ports.clone().unwrap_or_default();
+    let volumes = info.volumes.clone().unwrap_or_default();
+    let command = (!info.command.is_empty()).then_some(info.command.as_str());
+    let container_name = format!("devspin-{}-{}", info.project_name, info.service_name);
+
+    let spec = crate::process::container::ContainerSpec {
+        container_name: &container_name,
+        image,
+        command,
+        ports: &ports,
+        volumes: &volumes,
+        env: &env,
+    };
+
+    match crate::process::container::start_container(spec).await {
+        Ok(container_id) => {
+            let mut state = get_global_state();
+            state.reinsert_container(info, container_id);
+        }
+        Err(e) => error!(
+            "failed to restart container service {} ({}): {}",
+            info.service_name, info.project_name, e
+        ),
+    }
+}
@@ -0,0 +1,76 @@
+//! Feeds `devspin status`'s `recent_logs`/`last_output` from each service's
+//! on-disk log file rather than piping the child process's own stdout/stderr
+//! a second time: `process::logging` already redirects both streams into one
+//! append-only file per service, so tailing that file captures the same
+//! output without a second redirection path or needing the spawned child to
+//! be piped. Each tracked pid keeps a capped ring buffer of its most recent
+//! lines, refreshed incrementally (by byte offset, not a full reread)
+//! whenever `devspin status` asks for a service's logs.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+/// Most lines kept per service, regardless of how many a caller asks for via
+/// `--tail`.
+const CAPACITY: usize = 200;
+
+struct Tail {
+    lines: VecDeque<String>,
+    offset: u64,
+}
+
+static TAILS: Lazy<Mutex<HashMap<u32, Tail>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Reads any bytes appended to `log_path` since the last call for this pid,
+/// folds complete lines into the ring buffer, and returns its current
+/// contents (oldest first, most recent last) along with whether any new
+/// lines were read on this call, so callers like `devspin status
+/// --watch-idle` can treat fresh log output as a sign of life without
+/// re-deriving it themselves. A trailing partial line (the process hasn't
+/// finished writing it yet) is left for the next call.
+pub fn poll(pid: u32, log_path: &Path) -> (Vec<String>, bool) {
+    let mut tails = TAILS.lock().unwrap();
+    let tail = tails.entry(pid).or_insert_with(|| Tail {
+        lines: VecDeque::new(),
+        offset: 0,
+    });
+    let offset_before = tail.offset;
+
+    if let Ok(mut file) = File::open(log_path) {
+        if file.seek(SeekFrom::Start(tail.offset)).is_ok() {
+            let mut reader = BufReader::new(&file);
+            let mut line = String::new();
+            loop {
+                line.clear();
+                match reader.read_line(&mut line) {
+                    Ok(0) => break,
+                    Ok(bytes_read) if line.ends_with('\n') => {
+                        tail.offset += bytes_read as u64;
+                        tail.lines.push_back(line.trim_end().to_string());
+                        while tail.lines.len() > CAPACITY {
+                            tail.lines.pop_front();
+                        }
+                    }
+                    // Partial line at EOF; wait for the rest on the next poll
+                    // instead of reporting a line that's still being written.
+                    Ok(_) => break,
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+
+    (tail.lines.iter().cloned().collect(), tail.offset != offset_before)
+}
+
+/// Drops a pid's tail state once its process is no longer tracked, so this
+/// map doesn't grow forever across the lifetime of a long-running
+/// `devspin status --follow` session.
+pub fn forget(pid: u32) {
+    TAILS.lock().unwrap().remove(&pid);
+}
@@ -0,0 +1,25 @@
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::PathBuf;
+use std::process::Stdio;
+
+pub fn log_path_for(project_name: &str, service_name: &str) -> PathBuf {
+    PathBuf::from("logs")
+        .join(project_name)
+        .join(format!("{}.log", service_name))
+}
+
+/// Opens (creating if needed) the append-only log file for a service and
+/// returns its path plus two independent `Stdio` handles so stdout and
+/// stderr both land in the same file, in the order they were written.
+pub fn open_service_log(project_name: &str, service_name: &str) -> io::Result<(PathBuf, Stdio, Stdio)> {
+    let path = log_path_for(project_name, service_name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let stdout_file = OpenOptions::new().create(true).append(true).open(&path)?;
+    let stderr_file = stdout_file.try_clone()?;
+
+    Ok((path, Stdio::from(stdout_file), Stdio::from(stderr_file)))
+}
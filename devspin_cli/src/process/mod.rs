@@ -0,0 +1,18 @@
+pub mod activity;
+pub mod container;
+pub mod enforcer;
+pub mod global;
+pub mod health;
+pub mod health_cache;
+pub mod health_supervisor;
+pub mod log_tail;
+pub mod logging;
+pub mod manager;
+pub mod publisher;
+pub mod reaper;
+pub mod resources;
+pub mod signal;
+pub mod state;
+pub mod state_matcher;
+pub mod supervisor;
+pub mod watcher;
@@ -0,0 +1,58 @@
+//! Publishes `devspin status` snapshots to a NATS subject, per `devspin
+//! status --nats <url>`, so several machines/projects can be watched from
+//! one place instead of SSHing in to run `devspin status` on each.
+
+use serde::Serialize;
+
+use crate::cli::status::LiveServiceState;
+use crate::error::{Result, ToolError};
+
+/// Wire shape published to NATS: the raw service list plus a stable
+/// `agent_id` so a subscriber aggregating snapshots from several machines
+/// can tell which one each snapshot came from.
+#[derive(Debug, Serialize)]
+struct StatusSnapshot<'a> {
+    agent_id: String,
+    services: &'a [LiveServiceState],
+}
+
+/// Connects to `url` and publishes one status snapshot to `subject`.
+/// Connects fresh on every call rather than keeping a long-lived client:
+/// `devspin status --follow`'s refresh interval is measured in seconds, far
+/// coarser than reconnect overhead, and it keeps this module stateless.
+pub async fn publish(url: &str, subject: &str, services: &[LiveServiceState]) -> Result<()> {
+    let client = async_nats::connect(url)
+        .await
+        .map_err(|e| ToolError::ProcessError(format!("failed to connect to NATS at {}: {}", url, e)))?;
+
+    let snapshot = StatusSnapshot {
+        agent_id: agent_id(),
+        services,
+    };
+    let payload = serde_json::to_vec(&snapshot)
+        .map_err(|e| ToolError::ProcessError(format!("failed to serialize status snapshot: {}", e)))?;
+
+    client
+        .publish(subject.to_string(), payload.into())
+        .await
+        .map_err(|e| ToolError::ProcessError(format!("failed to publish to NATS subject {}: {}", subject, e)))?;
+    client
+        .flush()
+        .await
+        .map_err(|e| ToolError::ProcessError(format!("failed to flush NATS publish: {}", e)))?;
+
+    Ok(())
+}
+
+/// A stable identifier for this machine, so a subscriber aggregating
+/// snapshots from several agents can tell them apart. Defaults to the
+/// system hostname; override with `DEVSPIN_AGENT_ID` for environments where
+/// the hostname isn't meaningful (e.g. ephemeral containers).
+fn agent_id() -> String {
+    std::env::var("DEVSPIN_AGENT_ID").unwrap_or_else(|_| {
+        hostname::get()
+            .ok()
+            .and_then(|name| name.into_string().ok())
+            .unwrap_or_else(|| "unknown-host".to_string())
+    })
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use log::{info, warn};
+use tokio::time::sleep;
+
+use crate::process::global::get_global_state;
+
+const REAP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Starts the background task that stops lazy (on-demand) services once
+/// they've been idle past their configured `idle_timeout`. The next
+/// connection to their listener respawns them.
+pub fn spawn_idle_reaper() {
+    tokio::spawn(async move {
+        loop {
+            sleep(REAP_INTERVAL).await;
+            reap_tick().await;
+        }
+    });
+}
+
+async fn reap_tick() {
+    let stopped: Vec<crate::process::state::ProcessInfo> = {
+        let mut state = get_global_state();
+
+        let idle_pids: Vec<u32> = state
+            .get_all_processes()
+            .values()
+            .filter(|p| p.info.is_lazy)
+            .filter_map(|p| {
+                let timeout_secs = p.info.idle_timeout_secs?;
+                let idle_for = p.info.last_active.elapsed().ok()?;
+                (idle_for > Duration::from_secs(timeout_secs)).then_some(p.info.pid)
+            })
+            .collect();
+
+        idle_pids.into_iter().filter_map(|pid| state.stop_for_idle(pid)).collect()
+    };
+
+    for info in stopped {
+        // `stop_for_idle` only untracks a container; stop it here.
+        if let Some(container_id) = &info.container_id {
+            if let Err(e) = crate::process::container::stop_container(container_id).await {
+                warn!("failed to stop idle container for {}: {}", info.service_name, e);
+            }
+        }
+        info!(
+            "stopped idle lazy service {} ({}) after {}s of inactivity",
+            info.service_name,
+            info.project_name,
+            info.idle_timeout_secs.unwrap_or(0)
+        );
+    }
+}
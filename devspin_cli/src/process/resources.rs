@@ -0,0 +1,135 @@
+//! Resource-usage sampling for `devspin status --resources`, backed by the
+//! `sysinfo` crate. `Process::cpu_usage()` only reports a meaningful
+//! percentage once a process has been refreshed twice with some real time
+//! between the two refreshes, so a single `System` is kept alive for the
+//! whole command invocation (via this module's global, mirroring
+//! `process::global`'s singleton) instead of being rebuilt on every
+//! `follow_mode` tick.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use sysinfo::{Pid, ProcessRefreshKind, System};
+
+use crate::cli::status::ResourceUsage;
+
+static SYSTEM: Lazy<Mutex<System>> = Lazy::new(|| Mutex::new(System::new()));
+
+/// Samples CPU/memory/listening-port usage for `pid`. Returns `None` if the
+/// process can no longer be found, e.g. it exited between the status scan
+/// that found it and this call.
+pub fn sample(pid: u32) -> Option<ResourceUsage> {
+    let mut system = SYSTEM.lock().unwrap();
+    let sys_pid = Pid::from_u32(pid);
+    system.refresh_process_specifics(sys_pid, ProcessRefreshKind::everything());
+    let process = system.process(sys_pid)?;
+
+    Some(ResourceUsage {
+        cpu_percent: process.cpu_usage(),
+        memory_mb: process.memory() / 1024,
+        listening_ports: listening_ports(pid),
+    })
+}
+
+/// Walks `/proc/<pid>/fd` for open sockets and cross-references them against
+/// `/proc/net/tcp{,6}` to find which ones are local listening sockets. There's
+/// no portable equivalent outside Linux, so this is a no-op elsewhere.
+#[cfg(target_os = "linux")]
+fn listening_ports(pid: u32) -> Option<Vec<u16>> {
+    let socket_inodes = socket_inodes_for_pid(pid)?;
+    if socket_inodes.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut ports: Vec<u16> = ["/proc/net/tcp", "/proc/net/tcp6"]
+        .iter()
+        .flat_map(|table| listening_ports_in_table(table, &socket_inodes))
+        .collect();
+    ports.sort_unstable();
+    ports.dedup();
+    Some(ports)
+}
+
+#[cfg(target_os = "linux")]
+fn socket_inodes_for_pid(pid: u32) -> Option<HashSet<u64>> {
+    let entries = std::fs::read_dir(format!("/proc/{}/fd", pid)).ok()?;
+
+    Some(
+        entries
+            .flatten()
+            .filter_map(|entry| std::fs::read_link(entry.path()).ok())
+            .filter_map(|target| {
+                target
+                    .to_str()?
+                    .strip_prefix("socket:[")?
+                    .strip_suffix(']')?
+                    .parse()
+                    .ok()
+            })
+            .collect(),
+    )
+}
+
+/// A listening socket's `st` (connection state) field in `/proc/net/tcp{,6}`.
+#[cfg(target_os = "linux")]
+const TCP_LISTEN_STATE: &str = "0A";
+
+#[cfg(target_os = "linux")]
+fn listening_ports_in_table(path: &str, socket_inodes: &HashSet<u64>) -> Vec<u16> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    contents
+        .lines()
+        .skip(1) // header row
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let local_address = fields.get(1)?;
+            let state = fields.get(3)?;
+            let inode: u64 = fields.get(9)?.parse().ok()?;
+            if *state != TCP_LISTEN_STATE || !socket_inodes.contains(&inode) {
+                return None;
+            }
+            let port_hex = local_address.split(':').nth(1)?;
+            u16::from_str_radix(port_hex, 16).ok()
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn listening_ports(_pid: u32) -> Option<Vec<u16>> {
+    None
+}
+
+/// Whether `pid` currently has any established (not just listening) TCP
+/// connection, used by `devspin status --watch-idle` as a sign of life for
+/// services that expose a socket instead of (or in addition to) writing
+/// logs.
+#[cfg(target_os = "linux")]
+pub fn has_connection_activity(pid: u32) -> bool {
+    let Some(socket_inodes) = socket_inodes_for_pid(pid) else {
+        return false;
+    };
+    if socket_inodes.is_empty() {
+        return false;
+    }
+
+    const TCP_ESTABLISHED_STATE: &str = "01";
+    ["/proc/net/tcp", "/proc/net/tcp6"].iter().any(|table| {
+        std::fs::read_to_string(table).is_ok_and(|contents| {
+            contents.lines().skip(1).any(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let state = fields.get(3);
+                let inode = fields.get(9).and_then(|s| s.parse::<u64>().ok());
+                state == Some(&TCP_ESTABLISHED_STATE) && inode.is_some_and(|i| socket_inodes.contains(&i))
+            })
+        })
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn has_connection_activity(_pid: u32) -> bool {
+    false
+}
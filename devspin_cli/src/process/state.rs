@@ -0,0 +1,444 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Child;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::configs::yaml_parser::{HealthCheck, RestartPolicy, Service, StateMatcherConfig};
+use crate::error::Result;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ProcessStatus {
+    Running,
+    Stopped,
+    Error(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub service_name: String,
+    pub project_name: String,
+    pub command: String,
+    pub working_dir: Option<String>,
+    pub start_time: SystemTime,
+    pub status: ProcessStatus,
+    /// Service names this one depends on, snapshotted from the project
+    /// config at spawn time so shutdown ordering doesn't need to reload it.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    pub restart_policy: RestartPolicy,
+    /// Base restart delay; the supervisor doubles this per consecutive
+    /// failure (capped at `max_delay_secs`) for exponential backoff.
+    pub restart_delay_secs: u64,
+    #[serde(default = "default_max_delay_secs")]
+    pub max_delay_secs: u64,
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// How long this service must stay up before a later crash resets the
+    /// consecutive-failure count instead of adding to it.
+    #[serde(default = "default_success_threshold_secs")]
+    pub success_threshold_secs: u64,
+    /// Consecutive crashes since the last clean exit or the last run that
+    /// outlasted `success_threshold_secs`, used by the supervisor to cap
+    /// retries.
+    #[serde(default)]
+    pub failure_count: u32,
+    /// Whether this process was spawned on-demand by a lazy-activation
+    /// listener rather than eagerly at `devspin start`.
+    #[serde(default)]
+    pub is_lazy: bool,
+    pub idle_timeout_secs: Option<u64>,
+    /// Last time a connection was proxied to this service; only meaningful
+    /// for lazy services, used by the idle reaper.
+    pub last_active: SystemTime,
+    /// Where this service's stdout/stderr are being appended to, if log
+    /// capture was set up for it. Read by `devspin logs`.
+    pub log_path: Option<PathBuf>,
+    /// Health check config snapshotted from the project at spawn time, if
+    /// any; consulted by the background health monitor.
+    pub health_check: Option<HealthCheck>,
+    /// Minimum healthy instances of this service the enforcer should keep
+    /// running.
+    #[serde(default = "default_min_instances")]
+    pub min_instances: u32,
+    /// Last time the health monitor actually probed this process.
+    pub last_health_check: SystemTime,
+    /// Docker container id, for `type: docker`/`type: compose` services.
+    /// `None` for a plain local process.
+    pub container_id: Option<String>,
+    /// Image, ports, volumes and env snapshotted from the project config at
+    /// spawn time, so the supervisor/enforcer can recreate the container on
+    /// restart without going back to the YAML.
+    pub image: Option<String>,
+    pub ports: Option<Vec<String>>,
+    pub volumes: Option<Vec<String>>,
+    pub container_env: Option<HashMap<String, String>>,
+    /// Threshold-based health conditions snapshotted from the project config
+    /// at spawn time, consulted by `devspin status` alongside `health_check`.
+    #[serde(default)]
+    pub state_matchers: Vec<StateMatcherConfig>,
+    /// `devspin status --watch-idle`'s idle timeout for this service, if
+    /// any, snapshotted from the project config at spawn time.
+    pub watch_idle_timeout_secs: Option<u64>,
+}
+
+fn default_min_instances() -> u32 {
+    1
+}
+
+fn default_max_delay_secs() -> u64 {
+    60
+}
+
+fn default_max_retries() -> u32 {
+    5
+}
+
+fn default_success_threshold_secs() -> u64 {
+    10
+}
+
+/// What's actually backing a tracked service: either a local OS child we
+/// spawned ourselves, or a Docker container managed through the Engine API.
+/// Containers have no OS process of this program's own, so operations that
+/// only make sense for one kind (waiting for exit, sending a signal) dispatch
+/// on this instead of assuming a `Child` everywhere.
+pub enum ProcessHandle {
+    Local(Child),
+    Container { container_id: String },
+}
+
+/// A process we're actively supervising: the handle we spawned it with,
+/// plus the metadata we report through `ProcessManager`.
+pub struct RunningProcess {
+    pub info: ProcessInfo,
+    pub handle: ProcessHandle,
+}
+
+/// Synthetic pids for container-backed services, which have no OS process of
+/// this program's own to key the tracking map by. Starts well above any real
+/// PID range so the two never collide.
+static NEXT_CONTAINER_PID: std::sync::atomic::AtomicU32 =
+    std::sync::atomic::AtomicU32::new(1_000_000_000);
+
+#[derive(Default)]
+pub struct ProcessState {
+    processes: HashMap<u32, RunningProcess>,
+    /// Processes the supervisor gave up on, with the reason recorded in
+    /// their status. Readable through `get_terminated_processes`;
+    /// `devspin status` does not list them.
+    terminated: Vec<ProcessInfo>,
+}
+
+impl ProcessState {
+    pub fn new() -> Self {
+        ProcessState {
+            processes: HashMap::new(),
+            terminated: Vec::new(),
+        }
+    }
+
+    pub fn add_process(
+        &mut self,
+        child: Child,
+        service: &Service,
+        project_name: &str,
+        working_dir: Option<String>,
+        log_path: Option<PathBuf>,
+    ) -> Result<()> {
+        let pid = child.id();
+        let info = ProcessInfo {
+            pid,
+            service_name: service.name.clone(),
+            project_name: project_name.to_string(),
+            command: service.command.clone(),
+            working_dir,
+            start_time: SystemTime::now(),
+            status: ProcessStatus::Running,
+            dependencies: service.dependencies.clone(),
+            restart_policy: service.restart,
+            restart_delay_secs: service.restart_delay,
+            max_delay_secs: service.max_delay_secs,
+            max_retries: service.max_retries,
+            success_threshold_secs: service.success_threshold_secs,
+            failure_count: 0,
+            is_lazy: service.lazy,
+            idle_timeout_secs: service.idle_timeout,
+            last_active: SystemTime::now(),
+            log_path,
+            health_check: service.health_check.clone(),
+            min_instances: service.min_instances,
+            last_health_check: SystemTime::now(),
+            container_id: None,
+            image: None,
+            ports: None,
+            volumes: None,
+            container_env: None,
+            state_matchers: service.state_matchers.clone(),
+            watch_idle_timeout_secs: service.watch_idle_timeout_secs,
+        };
+
+        self.processes.insert(
+            pid,
+            RunningProcess {
+                info,
+                handle: ProcessHandle::Local(child),
+            },
+        );
+        Ok(())
+    }
+
+    /// Registers a container-backed service under a synthetic pid, since a
+    /// Docker container has no OS process of this program's own to key the
+    /// tracking map by. Otherwise mirrors `add_process`.
+    pub fn add_container_process(
+        &mut self,
+        container_id: String,
+        service: &Service,
+        project_name: &str,
+        log_path: Option<PathBuf>,
+    ) -> Result<u32> {
+        let pid = NEXT_CONTAINER_PID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let info = ProcessInfo {
+            pid,
+            service_name: service.name.clone(),
+            project_name: project_name.to_string(),
+            command: service.command.clone(),
+            working_dir: service.working_dir.clone(),
+            start_time: SystemTime::now(),
+            status: ProcessStatus::Running,
+            dependencies: service.dependencies.clone(),
+            restart_policy: service.restart,
+            restart_delay_secs: service.restart_delay,
+            max_delay_secs: service.max_delay_secs,
+            max_retries: service.max_retries,
+            success_threshold_secs: service.success_threshold_secs,
+            failure_count: 0,
+            is_lazy: service.lazy,
+            idle_timeout_secs: service.idle_timeout,
+            last_active: SystemTime::now(),
+            log_path,
+            health_check: service.health_check.clone(),
+            min_instances: service.min_instances,
+            last_health_check: SystemTime::now(),
+            container_id: Some(container_id.clone()),
+            image: service.image.clone(),
+            ports: service.ports.clone(),
+            volumes: service.volumes.clone(),
+            container_env: service.env.clone(),
+            state_matchers: service.state_matchers.clone(),
+            watch_idle_timeout_secs: service.watch_idle_timeout_secs,
+        };
+
+        self.processes.insert(
+            pid,
+            RunningProcess {
+                info,
+                handle: ProcessHandle::Container { container_id },
+            },
+        );
+        Ok(pid)
+    }
+
+    pub fn remove_process(&mut self, pid: u32) -> Result<()> {
+        self.processes.remove(&pid);
+        super::log_tail::forget(pid);
+        super::state_matcher::forget(pid);
+        super::activity::forget(pid);
+        Ok(())
+    }
+
+    pub fn get_all_processes(&self) -> &HashMap<u32, RunningProcess> {
+        &self.processes
+    }
+
+    pub fn get_terminated_processes(&self) -> &[ProcessInfo] {
+        &self.terminated
+    }
+
+    pub fn is_service_running(&self, project_name: &str, service_name: &str) -> bool {
+        self.processes.values().any(|p| {
+            p.info.project_name == project_name
+                && p.info.service_name == service_name
+                && matches!(p.info.status, ProcessStatus::Running)
+        })
+    }
+
+    pub fn process_count(&self) -> usize {
+        self.processes.len()
+    }
+
+    /// Non-blocking check for whether a tracked child has exited. Containers
+    /// have no local `Child` to poll this way, so this always reports "still
+    /// running" for one; the health monitor (see `process::health`) flags a
+    /// dead container only when its `health_check` probe fails.
+    pub fn try_wait(&mut self, pid: u32) -> Result<Option<std::process::ExitStatus>> {
+        match self.processes.get_mut(&pid) {
+            Some(running) => match &mut running.handle {
+                ProcessHandle::Local(child) => Ok(child.try_wait()?),
+                ProcessHandle::Container { .. } => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Removes a process that the supervisor observed exiting, bumping or
+    /// resetting its failure count, and returns its tracked info so the
+    /// caller can decide whether to restart it.
+    pub fn take_exited(&mut self, pid: u32, succeeded: bool) -> Option<ProcessInfo> {
+        let running = self.processes.remove(&pid)?;
+        super::log_tail::forget(pid);
+        super::state_matcher::forget(pid);
+        super::activity::forget(pid);
+        let mut info = running.info;
+
+        // A crash after a long enough healthy run counts as a fresh failure
+        // rather than another consecutive one.
+        let stayed_up_long_enough = info
+            .start_time
+            .elapsed()
+            .map(|uptime| uptime >= Duration::from_secs(info.success_threshold_secs))
+            .unwrap_or(false);
+
+        info.status = if succeeded {
+            ProcessStatus::Stopped
+        } else {
+            ProcessStatus::Error("process exited with a non-zero status".to_string())
+        };
+        info.failure_count = if succeeded || stayed_up_long_enough {
+            0
+        } else {
+            info.failure_count + 1
+        };
+
+        Some(info)
+    }
+
+    /// Re-registers a freshly respawned child under the same service
+    /// identity as `info`, keeping its restart bookkeeping intact.
+    pub fn reinsert(&mut self, mut info: ProcessInfo, child: Child) {
+        info.pid = child.id();
+        info.start_time = SystemTime::now();
+        info.status = ProcessStatus::Running;
+        info.last_health_check = SystemTime::now();
+        self.processes.insert(
+            info.pid,
+            RunningProcess {
+                info,
+                handle: ProcessHandle::Local(child),
+            },
+        );
+    }
+
+    /// Container equivalent of `reinsert`, used when the supervisor/enforcer
+    /// recreates a container for a service that crashed or fell below
+    /// `min_instances`.
+    pub fn reinsert_container(&mut self, mut info: ProcessInfo, container_id: String) {
+        info.pid = NEXT_CONTAINER_PID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        info.start_time = SystemTime::now();
+        info.status = ProcessStatus::Running;
+        info.last_health_check = SystemTime::now();
+        info.container_id = Some(container_id.clone());
+        self.processes.insert(
+            info.pid,
+            RunningProcess {
+                info,
+                handle: ProcessHandle::Container { container_id },
+            },
+        );
+    }
+
+    /// Updates a tracked process's status, e.g. from a health-check result.
+    pub fn set_status(&mut self, pid: u32, status: ProcessStatus) {
+        if let Some(running) = self.processes.get_mut(&pid) {
+            running.info.status = status;
+        }
+    }
+
+    /// Records that the health monitor just probed this process.
+    pub fn touch_health_check(&mut self, pid: u32) {
+        if let Some(running) = self.processes.get_mut(&pid) {
+            running.info.last_health_check = SystemTime::now();
+        }
+    }
+
+    /// Records that the supervisor has given up retrying this service.
+    pub fn mark_terminated(&mut self, mut info: ProcessInfo, reason: String) {
+        info.status = ProcessStatus::Error(reason);
+        self.terminated.push(info);
+    }
+
+    /// Marks a lazy service as having just seen traffic, so the idle
+    /// reaper doesn't stop it out from under an active connection.
+    pub fn touch_last_active(&mut self, project_name: &str, service_name: &str) {
+        if let Some(running) = self.processes.values_mut().find(|p| {
+            p.info.project_name == project_name && p.info.service_name == service_name
+        }) {
+            running.info.last_active = SystemTime::now();
+        }
+    }
+
+    /// Removes the tracked process for `service_name`, returning its info so
+    /// the caller can respawn it (used by `devspin start --watch` to restart
+    /// a service after a file change). A local child is killed outright; a
+    /// container is only untracked here since tearing it down needs an
+    /// `.await` this (synchronous) method can't do — the caller is expected
+    /// to call `process::container::stop_container` itself when
+    /// `container_id` is `Some`.
+    pub fn take_by_service_name(&mut self, project_name: &str, service_name: &str) -> Option<ProcessInfo> {
+        let pid = self
+            .processes
+            .values()
+            .find(|p| p.info.project_name == project_name && p.info.service_name == service_name)
+            .map(|p| p.info.pid)?;
+
+        let mut running = self.processes.remove(&pid)?;
+        if let ProcessHandle::Local(child) = &mut running.handle {
+            let _ = child.kill();
+        }
+        super::log_tail::forget(running.info.pid);
+        super::state_matcher::forget(running.info.pid);
+        super::activity::forget(running.info.pid);
+        Some(running.info)
+    }
+
+    /// Removes a tracked process that's in `ProcessStatus::Error`, returning
+    /// its info so the caller can finish tearing it down (stopping its
+    /// container, if any) before spawning a replacement. Returns `None` if
+    /// `pid` isn't tracked or isn't actually in `Error` - callers shouldn't
+    /// reap a process out from under a status change that raced them.
+    /// Mirrors `take_by_service_name`: a local child is killed outright; a
+    /// container is only untracked here since stopping it needs an
+    /// `.await` this (synchronous) method can't do.
+    pub fn reap_errored(&mut self, pid: u32) -> Option<ProcessInfo> {
+        if !matches!(self.processes.get(&pid)?.info.status, ProcessStatus::Error(_)) {
+            return None;
+        }
+
+        let mut running = self.processes.remove(&pid)?;
+        if let ProcessHandle::Local(child) = &mut running.handle {
+            let _ = child.kill();
+        }
+        super::log_tail::forget(pid);
+        super::state_matcher::forget(pid);
+        super::activity::forget(pid);
+        Some(running.info)
+    }
+
+    /// Stops a lazy service that has been idle past its `idle_timeout`,
+    /// removing it from the tracked set so the next connection respawns it.
+    /// See `take_by_service_name` for why containers aren't torn down here.
+    pub fn stop_for_idle(&mut self, pid: u32) -> Option<ProcessInfo> {
+        let mut running = self.processes.remove(&pid)?;
+        if let ProcessHandle::Local(child) = &mut running.handle {
+            let _ = child.kill();
+        }
+        super::log_tail::forget(running.info.pid);
+        super::state_matcher::forget(running.info.pid);
+        super::activity::forget(running.info.pid);
+        running.info.status = ProcessStatus::Stopped;
+        Some(running.info)
+    }
+}
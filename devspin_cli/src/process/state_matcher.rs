@@ -0,0 +1,161 @@
+//! Pluggable, duration-gated health conditions for `devspin status`. A
+//! `StateMatcher` answers a single yes/no question about a resource sample
+//! or process state; this module also tracks, per process, how long each
+//! matcher's answer has been continuously "yes" for, so a momentary CPU
+//! spike or a single missed sample doesn't flap a service's reported health
+//! the way a bare threshold check would.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+
+use crate::cli::status::ResourceUsage;
+use crate::configs::yaml_parser::StateMatcherConfig;
+use crate::process::state::{ProcessInfo, ProcessStatus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchOutcome {
+    Matched,
+    NotMatched,
+}
+
+pub trait StateMatcher: Send + Sync {
+    fn matches(&self, sample: Option<&ResourceUsage>, proc: &ProcessInfo) -> MatchOutcome;
+    /// How long `matches` must keep returning `Matched` before this counts as
+    /// a sustained condition rather than a momentary blip.
+    fn duration(&self) -> Duration;
+    /// Shown in the unhealthy summary / `--errors` output when this is the
+    /// matcher that tripped.
+    fn description(&self) -> String;
+}
+
+struct CpuAbove {
+    percent: f32,
+    duration: Duration,
+}
+
+impl StateMatcher for CpuAbove {
+    fn matches(&self, sample: Option<&ResourceUsage>, _proc: &ProcessInfo) -> MatchOutcome {
+        match sample {
+            Some(usage) if usage.cpu_percent > self.percent => MatchOutcome::Matched,
+            _ => MatchOutcome::NotMatched,
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn description(&self) -> String {
+        format!("CPU above {:.0}% for {}s", self.percent, self.duration.as_secs())
+    }
+}
+
+struct MemoryAbove {
+    mb: u64,
+    duration: Duration,
+}
+
+impl StateMatcher for MemoryAbove {
+    fn matches(&self, sample: Option<&ResourceUsage>, _proc: &ProcessInfo) -> MatchOutcome {
+        match sample {
+            Some(usage) if usage.memory_mb > self.mb => MatchOutcome::Matched,
+            _ => MatchOutcome::NotMatched,
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn description(&self) -> String {
+        format!("memory above {} MB for {}s", self.mb, self.duration.as_secs())
+    }
+}
+
+struct Liveness {
+    duration: Duration,
+}
+
+impl StateMatcher for Liveness {
+    fn matches(&self, _sample: Option<&ResourceUsage>, proc: &ProcessInfo) -> MatchOutcome {
+        if matches!(proc.status, ProcessStatus::Running) {
+            MatchOutcome::NotMatched
+        } else {
+            MatchOutcome::Matched
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn description(&self) -> String {
+        "process not running".to_string()
+    }
+}
+
+/// Builds the concrete matchers a service declared in its config.
+pub fn build_matchers(configs: &[StateMatcherConfig]) -> Vec<Box<dyn StateMatcher>> {
+    configs
+        .iter()
+        .map(|config| -> Box<dyn StateMatcher> {
+            match config {
+                StateMatcherConfig::CpuAbove { percent, duration_secs } => Box::new(CpuAbove {
+                    percent: *percent,
+                    duration: Duration::from_secs(*duration_secs),
+                }),
+                StateMatcherConfig::MemoryAbove { mb, duration_secs } => Box::new(MemoryAbove {
+                    mb: *mb,
+                    duration: Duration::from_secs(*duration_secs),
+                }),
+                StateMatcherConfig::Liveness { duration_secs } => Box::new(Liveness {
+                    duration: Duration::from_secs(*duration_secs),
+                }),
+            }
+        })
+        .collect()
+}
+
+/// How long each (pid, matcher index) pair's condition has held
+/// continuously. Global like `process::resources`, and keyed by pid like
+/// `process::log_tail`, so the tracking survives across `follow_mode` ticks
+/// without threading extra state through `StatusArgs`.
+static SINCE_MATCHED: Lazy<Mutex<HashMap<(u32, usize), Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Evaluates every configured matcher for a process, returning the
+/// description of the first one whose condition has held continuously for
+/// at least its configured duration, if any.
+pub fn tripped_matcher(
+    pid: u32,
+    matchers: &[Box<dyn StateMatcher>],
+    sample: Option<&ResourceUsage>,
+    proc: &ProcessInfo,
+) -> Option<String> {
+    let mut since_matched = SINCE_MATCHED.lock().unwrap();
+    let mut tripped = None;
+
+    for (index, matcher) in matchers.iter().enumerate() {
+        let key = (pid, index);
+        match matcher.matches(sample, proc) {
+            MatchOutcome::Matched => {
+                let started = *since_matched.entry(key).or_insert_with(Instant::now);
+                if tripped.is_none() && started.elapsed() >= matcher.duration() {
+                    tripped = Some(matcher.description());
+                }
+            }
+            MatchOutcome::NotMatched => {
+                since_matched.remove(&key);
+            }
+        }
+    }
+
+    tripped
+}
+
+/// Drops a pid's condition-tracking state once it's no longer tracked.
+pub fn forget(pid: u32) {
+    SINCE_MATCHED.lock().unwrap().retain(|(tracked_pid, _), _| *tracked_pid != pid);
+}
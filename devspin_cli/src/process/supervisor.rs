@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use log::{error, info, warn};
+use tokio::time::sleep;
+
+use crate::configs::yaml_parser::RestartPolicy;
+use crate::process::global::get_global_state;
+use crate::process::state::ProcessInfo;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Starts the background supervisor loop that watches every tracked
+/// process and restarts it according to its configured `restart` policy.
+/// Safe to call once per `devspin start` invocation; the task runs for as
+/// long as the process does.
+pub fn spawn_supervisor() {
+    tokio::spawn(async move {
+        loop {
+            sleep(POLL_INTERVAL).await;
+            supervise_tick().await;
+        }
+    });
+}
+
+async fn supervise_tick() {
+    // Collect which tracked PIDs have exited (and how) without holding the
+    // state lock across an `.await`.
+    let exited: Vec<(u32, bool)> = {
+        let mut state = get_global_state();
+        let pids: Vec<u32> = state.get_all_processes().keys().copied().collect();
+
+        let mut exited = Vec::new();
+        for pid in pids {
+            match state.try_wait(pid) {
+                Ok(Some(status)) => exited.push((pid, status.success())),
+                Ok(None) => {}
+                Err(e) => warn!("failed to poll process {}: {}", pid, e),
+            }
+        }
+        exited
+    };
+
+    for (pid, succeeded) in exited {
+        handle_exit(pid, succeeded).await;
+    }
+}
+
+async fn handle_exit(pid: u32, succeeded: bool) {
+    let info = {
+        let mut state = get_global_state();
+        match state.take_exited(pid, succeeded) {
+            Some(info) => info,
+            None => return,
+        }
+    };
+
+    let should_restart = match info.restart_policy {
+        RestartPolicy::Always => true,
+        RestartPolicy::OnFailure => !succeeded,
+        RestartPolicy::Never => false,
+    };
+
+    if !should_restart {
+        if !succeeded {
+            warn!(
+                "service {} ({}) exited with a failure and its restart policy does not retry",
+                info.service_name, info.project_name
+            );
+        }
+        return;
+    }
+
+    if info.failure_count >= info.max_retries {
+        error!(
+            "service {} ({}) failed {} times in a row, giving up",
+            info.service_name, info.project_name, info.failure_count
+        );
+        let mut state = get_global_state();
+        state.mark_terminated(
+            info.clone(),
+            format!("gave up after {} consecutive failures", info.max_retries),
+        );
+        return;
+    }
+
+    // Exponential backoff: base_delay * 2^(attempt - 1), capped at max_delay.
+    let exponent = info.failure_count.saturating_sub(1).min(16);
+    let backoff_secs = info
+        .restart_delay_secs
+        .saturating_mul(2u64.saturating_pow(exponent))
+        .min(info.max_delay_secs);
+
+    info!(
+        "restarting service {} ({}) in {}s (attempt {}/{})",
+        info.service_name,
+        info.project_name,
+        backoff_secs,
+        info.failure_count,
+        info.max_retries
+    );
+
+    sleep(Duration::from_secs(backoff_secs)).await;
+    respawn(info).await;
+}
+
+async fn respawn(mut info: ProcessInfo) {
+    let mut command = std::process::Command::new("sh");
+    command.arg("-c").arg(&info.command);
+
+    if let Some(working_dir) = &info.working_dir {
+        command.current_dir(working_dir);
+    }
+
+    match crate::process::logging::open_service_log(&info.project_name, &info.service_name) {
+        Ok((log_path, stdout, stderr)) => {
+            command.stdout(stdout);
+            command.stderr(stderr);
+            info.log_path = Some(log_path);
+        }
+        Err(e) => warn!(
+            "failed to open log file for service {} ({}): {}",
+            info.service_name, info.project_name, e
+        ),
+    }
+
+    match command.spawn() {
+        Ok(child) => {
+            let mut state = get_global_state();
+            state.reinsert(info, child);
+        }
+        Err(e) => {
+            error!(
+                "failed to restart service {} ({}): {}",
+                info.service_name, info.project_name, e
+            );
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! `devspin start --watch`: polls each service's working directory for
+//! filesystem changes and restarts the affected service, plus anything
+//! that depends on it, once the changes settle.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use log::{info, warn};
+use tokio::time::sleep;
+
+use crate::configs::yaml_parser::{ProjectConfig, Service};
+use crate::process::global::get_global_state;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+struct WatchedService {
+    name: String,
+    roots: Vec<PathBuf>,
+    ignore: Vec<String>,
+    last_fingerprint: SystemTime,
+    pending_since: Option<Instant>,
+}
+
+/// Starts the background watch loop. Call once per `devspin start --watch`
+/// invocation; the task runs for as long as the process does.
+pub fn spawn_watcher(project: ProjectConfig, services: Vec<Service>) {
+    tokio::spawn(async move {
+        let mut watched: Vec<WatchedService> = services
+            .iter()
+            .map(|service| {
+                let ignore = service.ignore.clone().unwrap_or_default();
+                let roots = watch_roots(&project, service);
+                WatchedService {
+                    name: service.name.clone(),
+                    last_fingerprint: latest_mtime(&roots, &ignore),
+                    roots,
+                    ignore,
+                    pending_since: None,
+                }
+            })
+            .collect();
+
+        loop {
+            sleep(POLL_INTERVAL).await;
+
+            for watched_service in &mut watched {
+                let fingerprint = latest_mtime(&watched_service.roots, &watched_service.ignore);
+                if fingerprint > watched_service.last_fingerprint {
+                    watched_service.last_fingerprint = fingerprint;
+                    watched_service.pending_since = Some(Instant::now());
+                }
+            }
+
+            // Only fire once a service's changes have settled for the
+            // debounce window, so a burst of saves from an editor coalesces
+            // into a single restart.
+            let ready: Vec<String> = watched
+                .iter_mut()
+                .filter_map(|watched_service| {
+                    let since = watched_service.pending_since?;
+                    if since.elapsed() >= DEBOUNCE {
+                        watched_service.pending_since = None;
+                        Some(watched_service.name.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            for service_name in ready {
+                restart_with_dependents(&project, &services, &service_name).await;
+            }
+        }
+    });
+}
+
+fn watch_roots(project: &ProjectConfig, service: &Service) -> Vec<PathBuf> {
+    if let Some(paths) = &service.watch_paths {
+        paths.iter().map(|p| project.resolve_path(p)).collect()
+    } else if let Some(dir) = &service.working_dir {
+        vec![project.resolve_path(dir)]
+    } else {
+        vec![project.base_path.clone().unwrap_or_else(|| PathBuf::from("."))]
+    }
+}
+
+/// A coarse change fingerprint: the latest modification time seen while
+/// walking `roots`, skipping any path containing an `ignore` substring.
+fn latest_mtime(roots: &[PathBuf], ignore: &[String]) -> SystemTime {
+    let mut latest = SystemTime::UNIX_EPOCH;
+    for root in roots {
+        walk(root, ignore, &mut latest);
+    }
+    latest
+}
+
+fn walk(dir: &Path, ignore: &[String], latest: &mut SystemTime) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let path_str = path.to_string_lossy();
+
+        if ignore.iter().any(|pattern| path_str.contains(pattern.as_str())) {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        if let Ok(modified) = metadata.modified() {
+            if modified > *latest {
+                *latest = modified;
+            }
+        }
+
+        if metadata.is_dir() {
+            walk(&path, ignore, latest);
+        }
+    }
+}
+
+/// Restarts `changed_service` and, transitively, every service that depends
+/// on it, in the order `sort_services_by_dependencies` would start them in.
+async fn restart_with_dependents(project: &ProjectConfig, services: &[Service], changed_service: &str) {
+    for name in dependents_of(services, changed_service) {
+        if let Some(service) = services.iter().find(|s| s.name == name) {
+            info!("devspin watch: restarting {} after a file change", name);
+            restart_service(project, service).await;
+        }
+    }
+}
+
+/// Returns `changed` followed by every service that (transitively) depends
+/// on it, in the order they should be restarted.
+fn dependents_of(services: &[Service], changed: &str) -> Vec<String> {
+    let mut affected = vec![changed.to_string()];
+    let mut frontier = vec![changed.to_string()];
+
+    loop {
+        let next: Vec<String> = services
+            .iter()
+            .filter(|service| !affected.contains(&service.name))
+            .filter(|service| service.dependencies.iter().any(|dep| frontier.contains(dep)))
+            .map(|service| service.name.clone())
+            .collect();
+
+        if next.is_empty() {
+            break;
+        }
+
+        affected.extend(next.clone());
+        frontier = next;
+    }
+
+    // Breadth-first levels alone can put a service ahead of another affected
+    // service it depends on, so emit each only after those.
+    let mut ordered: Vec<String> = Vec::with_capacity(affected.len());
+    while ordered.len() < affected.len() {
+        let ready: Vec<String> = affected
+            .iter()
+            .filter(|name| !ordered.contains(*name))
+            .filter(|name| {
+                services.iter().find(|s| &s.name == *name).map_or(true, |s| {
+                    s.dependencies.iter().all(|dep| !affected.contains(dep) || ordered.contains(dep))
+                })
+            })
+            .cloned()
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        ordered.extend(ready);
+    }
+
+    ordered
+}
+
+async fn restart_service(project: &ProjectConfig, service: &Service) {
+    let template = {
+        let mut state = get_global_state();
+        state.take_by_service_name(&project.name, &service.name)
+    };
+
+    let Some(mut info) = template else {
+        warn!("devspin watch: {} isn't currently running, skipping restart", service.name);
+        return;
+    };
+
+    // File-watch restarts aren't wired up for container services yet: a
+    // changed source file doesn't mean the image needs rebuilding the way it
+    // means a local process needs re-running, so there's no good default
+    // here. Put the container back untouched rather than silently dropping
+    // its tracking entry.
+    if let Some(container_id) = info.container_id.clone() {
+        warn!(
+            "devspin watch: {} is a container service, file-watch restarts aren't supported for it",
+            service.name
+        );
+        let mut state = get_global_state();
+        state.reinsert_container(info, container_id);
+        return;
+    }
+
+    let mut command = std::process::Command::new("sh");
+    command.arg("-c").arg(&info.command);
+    if let Some(working_dir) = &info.working_dir {
+        command.current_dir(working_dir);
+    }
+
+    match crate::process::logging::open_service_log(&project.name, &service.name) {
+        Ok((log_path, stdout, stderr)) => {
+            command.stdout(stdout);
+            command.stderr(stderr);
+            info.log_path = Some(log_path);
+        }
+        Err(e) => warn!("failed to open log file for {}: {}", service.name, e),
+    }
+
+    match command.spawn() {
+        Ok(child) => {
+            let mut state = get_global_state();
+            state.reinsert(info, child);
+        }
+        Err(e) => warn!("devspin watch: failed to restart {}: {}", service.name, e),
+    }
+}